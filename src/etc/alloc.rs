@@ -0,0 +1,250 @@
+use std::ops::{AddAssign, Div};
+
+/// Allocations made while running a phase, as counted by the allocator of the `alloc-stats`
+/// feature. Without the feature every count stays zero.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AllocStats {
+    pub allocations: usize,
+    pub bytes: usize,
+    /// Most bytes that were live at once, on top of what was live when the phase started.
+    pub peak: usize,
+}
+
+/// Counts add up, while the peak is the highest of either side.
+impl AddAssign for AllocStats {
+    fn add_assign(&mut self, rhs: Self) {
+        self.allocations += rhs.allocations;
+        self.bytes += rhs.bytes;
+        self.peak = self.peak.max(rhs.peak);
+    }
+}
+
+/// Averages the counts over `rhs` runs. The peak is already a maximum and is kept as is.
+impl Div<u32> for AllocStats {
+    type Output = AllocStats;
+
+    fn div(self, rhs: u32) -> Self::Output {
+        AllocStats {
+            allocations: self.allocations / rhs as usize,
+            bytes: self.bytes / rhs as usize,
+            peak: self.peak,
+        }
+    }
+}
+
+/// The `AllocStats` of each phase of a solver.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Allocations {
+    pub parse: AllocStats,
+    pub part1: AllocStats,
+    pub part2: AllocStats,
+}
+
+impl AddAssign for Allocations {
+    fn add_assign(&mut self, rhs: Self) {
+        self.parse += rhs.parse;
+        self.part1 += rhs.part1;
+        self.part2 += rhs.part2;
+    }
+}
+
+impl Div<u32> for Allocations {
+    type Output = Allocations;
+
+    fn div(self, rhs: u32) -> Self::Output {
+        Allocations {
+            parse: self.parse / rhs,
+            part1: self.part1 / rhs,
+            part2: self.part2 / rhs,
+        }
+    }
+}
+
+pub fn enabled() -> bool {
+    cfg!(feature = "alloc-stats")
+}
+
+/// Runs `f` and counts the allocations it makes on the current thread.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, AllocStats) {
+    #[cfg(feature = "alloc-stats")]
+    {
+        let before = counting::snapshot();
+        counting::reset_peak();
+        let value = f();
+        let after = counting::snapshot();
+
+        let stats = AllocStats {
+            allocations: after.allocations - before.allocations,
+            bytes: after.bytes - before.bytes,
+            peak: after.peak.saturating_sub(before.live).max(0) as usize,
+        };
+        (value, stats)
+    }
+
+    #[cfg(not(feature = "alloc-stats"))]
+    {
+        (f(), AllocStats::default())
+    }
+}
+
+pub fn format_bytes(bytes: usize) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+    if bytes < 1024 {
+        return format!("{} B", bytes);
+    }
+
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while value >= 1024.0 && unit + 1 < UNITS.len() {
+        value /= 1024.0;
+        unit += 1;
+    }
+    format!("{:.1} {}", value, UNITS[unit])
+}
+
+/// A global allocator that forwards to the system allocator and keeps per-thread counters,
+/// so days solved in parallel with `--jobs` do not count each other's allocations.
+#[cfg(feature = "alloc-stats")]
+mod counting {
+    use std::{
+        alloc::{GlobalAlloc, Layout, System},
+        cell::Cell,
+    };
+
+    struct Counting;
+
+    #[global_allocator]
+    static GLOBAL: Counting = Counting;
+
+    #[derive(Clone, Copy)]
+    pub struct Counters {
+        pub allocations: usize,
+        pub bytes: usize,
+        /// Signed, because a thread can free memory that another thread allocated.
+        pub live: isize,
+        pub peak: isize,
+    }
+
+    thread_local! {
+        // Const initialized and without a destructor, so using it never allocates
+        static COUNTERS: Cell<Counters> = const {
+            Cell::new(Counters {
+                allocations: 0,
+                bytes: 0,
+                live: 0,
+                peak: 0,
+            })
+        };
+    }
+
+    fn update(f: impl FnOnce(&mut Counters)) {
+        // Fails while the thread is being torn down, when nothing is measured anyway
+        let _ = COUNTERS.try_with(|counters| {
+            let mut value = counters.get();
+            f(&mut value);
+            counters.set(value);
+        });
+    }
+
+    fn allocated(size: usize) {
+        update(|counters| {
+            counters.allocations += 1;
+            counters.bytes += size;
+            counters.live += size as isize;
+            counters.peak = counters.peak.max(counters.live);
+        });
+    }
+
+    fn freed(size: usize) {
+        update(|counters| counters.live -= size as isize);
+    }
+
+    pub fn snapshot() -> Counters {
+        COUNTERS.with(Cell::get)
+    }
+
+    pub fn reset_peak() {
+        update(|counters| counters.peak = counters.live);
+    }
+
+    unsafe impl GlobalAlloc for Counting {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            let ptr = System.alloc(layout);
+            if !ptr.is_null() {
+                allocated(layout.size());
+            }
+            ptr
+        }
+
+        unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+            let ptr = System.alloc_zeroed(layout);
+            if !ptr.is_null() {
+                allocated(layout.size());
+            }
+            ptr
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            System.dealloc(ptr, layout);
+            freed(layout.size());
+        }
+
+        unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+            let new = System.realloc(ptr, layout, new_size);
+            if !new.is_null() {
+                freed(layout.size());
+                allocated(new_size);
+            }
+            new
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_measure() {
+        let (v, stats) = measure(|| vec![0u64; 1000]);
+        assert_eq!(v.len(), 1000);
+
+        if enabled() {
+            assert_eq!(stats.allocations, 1);
+            assert_eq!(stats.bytes, 8000);
+            assert_eq!(stats.peak, 8000);
+
+            let (_, stats) = measure(|| drop(vec![0u8; 100]));
+            assert_eq!(stats.peak, 100);
+        } else {
+            assert_eq!(stats, AllocStats::default());
+        }
+    }
+
+    #[test]
+    fn test_combine() {
+        let mut total = AllocStats {
+            allocations: 4,
+            bytes: 100,
+            peak: 80,
+        };
+        total += AllocStats {
+            allocations: 2,
+            bytes: 50,
+            peak: 60,
+        };
+        assert_eq!(
+            total / 2,
+            AllocStats {
+                allocations: 3,
+                bytes: 75,
+                peak: 80
+            }
+        );
+
+        assert_eq!(format_bytes(512), "512 B");
+        assert_eq!(format_bytes(4200), "4.1 KiB");
+        assert_eq!(format_bytes(3 * 1024 * 1024), "3.0 MiB");
+    }
+}
@@ -0,0 +1,208 @@
+use std::{
+    collections::BTreeMap,
+    fmt::{Display, Formatter},
+    fs, io,
+    path::Path,
+    str::FromStr,
+};
+
+use super::Solution;
+
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Ok,
+    Wrong(String),
+    Unknown,
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Ok => write!(f, "OK"),
+            Verdict::Wrong(_) => write!(f, "WRONG"),
+            Verdict::Unknown => write!(f, "UNKNOWN"),
+        }
+    }
+}
+
+/// Expected answers for the real inputs, stored as a small subset of TOML:
+///
+/// ```toml
+/// [2023.day01]
+/// part1 = 142
+/// part2 = "some text"
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Answers {
+    expected: BTreeMap<(u16, u8, u8), String>,
+}
+
+impl Answers {
+    pub fn load(path: &Path) -> io::Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(contents) => contents.parse(),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Answers::default()),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Loads the expected answers of a single input, stored next to it as `partN = answer`
+    /// lines without a section. A missing file means that nothing is known.
+    pub fn load_for_input(path: &Path, year: u16, day: u8) -> io::Result<Self> {
+        let contents = match fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Answers::default()),
+            Err(err) => return Err(err),
+        };
+
+        let mut answers = Answers::default();
+        for (i, line) in contents.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let (part, value) = parse_entry(line).map_err(|reason| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("line {}: {}", i + 1, reason),
+                )
+            })?;
+            answers.insert(year, day, part, value);
+        }
+
+        Ok(answers)
+    }
+
+    pub fn get(&self, year: u16, day: u8, part: u8) -> Option<&str> {
+        self.expected.get(&(year, day, part)).map(String::as_str)
+    }
+
+    pub fn insert(&mut self, year: u16, day: u8, part: u8, expected: impl Into<String>) {
+        self.expected.insert((year, day, part), expected.into());
+    }
+
+    pub fn check(&self, year: u16, day: u8, part: u8, solution: &Solution) -> Verdict {
+        match self.get(year, day, part) {
+            None => Verdict::Unknown,
+            Some(expected)
+                if *solution != Solution::None && expected.parse() == Ok(solution.clone()) =>
+            {
+                Verdict::Ok
+            }
+            Some(expected) => Verdict::Wrong(expected.to_string()),
+        }
+    }
+}
+
+impl FromStr for Answers {
+    type Err = io::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = |line: usize, reason: &str| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("line {}: {}", line, reason),
+            )
+        };
+
+        let mut answers = Answers::default();
+        let mut day = None;
+
+        for (i, line) in s.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            if let Some(section) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+                let number = section
+                    .trim()
+                    .split_once(".day")
+                    .and_then(|(y, d)| Some((y.parse::<u16>().ok()?, d.parse::<u8>().ok()?)))
+                    .ok_or_else(|| invalid(i + 1, "expected a section like [2023.day01]"))?;
+                day = Some(number);
+                continue;
+            }
+
+            let (year, day) =
+                day.ok_or_else(|| invalid(i + 1, "answer outside of a [YYYY.dayNN] section"))?;
+            let (part, value) = parse_entry(line).map_err(|reason| invalid(i + 1, reason))?;
+
+            answers.insert(year, day, part, value);
+        }
+
+        Ok(answers)
+    }
+}
+
+/// Parses a `partN = answer` line, where the answer may be quoted.
+fn parse_entry(line: &str) -> Result<(u8, &str), &'static str> {
+    let (key, value) = line.split_once('=').ok_or("expected `partN = answer`")?;
+
+    let part = match key.trim() {
+        "part1" => 1,
+        "part2" => 2,
+        _ => return Err("expected part1 or part2"),
+    };
+
+    let value = value.trim();
+    let value = match value.strip_prefix('"') {
+        Some(quoted) => quoted.strip_suffix('"').ok_or("unterminated string")?,
+        None => value,
+    };
+
+    Ok((part, value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check() {
+        let answers = "
+# comment
+[2023.day01]
+part1 = 142
+part2 = \"ABC\"
+
+[2023.day24]
+part1 = 2
+"
+        .parse::<Answers>()
+        .unwrap();
+
+        assert_eq!(answers.check(2023, 1, 1, &Solution::U32(142)), Verdict::Ok);
+        assert_eq!(
+            answers.check(2023, 1, 1, &Solution::Usize(142)),
+            Verdict::Ok
+        );
+        assert_eq!(
+            answers.check(2023, 1, 2, &Solution::from("ABC")),
+            Verdict::Ok
+        );
+        assert_eq!(
+            answers.check(2023, 24, 1, &Solution::I128(3)),
+            Verdict::Wrong("2".to_string())
+        );
+        assert_eq!(
+            answers.check(2023, 24, 1, &Solution::None),
+            Verdict::Wrong("2".to_string())
+        );
+        assert_eq!(
+            answers.check(2023, 24, 2, &Solution::Usize(1)),
+            Verdict::Unknown
+        );
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert!("part1 = 1".parse::<Answers>().is_err());
+        assert!("[2023.day01]\npart3 = 1".parse::<Answers>().is_err());
+        assert!("[2023.dayone]".parse::<Answers>().is_err());
+        assert!("[day01]".parse::<Answers>().is_err());
+    }
+}
@@ -0,0 +1,267 @@
+use std::{
+    collections::BTreeMap,
+    fmt::{Display, Formatter},
+    fs, io,
+    path::Path,
+    str::FromStr,
+    time::Duration,
+};
+
+use super::timer::{as_ms, Timings};
+
+/// Changes smaller than this are reported as noise rather than a regression or improvement.
+const NOISE_THRESHOLD: f64 = 2.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Phase {
+    Parse,
+    Part1,
+    Part2,
+}
+
+impl Phase {
+    pub const ALL: [Phase; 3] = [Phase::Parse, Phase::Part1, Phase::Part2];
+
+    pub fn part(self) -> Option<u8> {
+        match self {
+            Phase::Parse => None,
+            Phase::Part1 => Some(1),
+            Phase::Part2 => Some(2),
+        }
+    }
+
+    pub fn of(self, timings: &Timings) -> Duration {
+        match self {
+            Phase::Parse => timings.parse,
+            Phase::Part1 => timings.part1,
+            Phase::Part2 => timings.part2,
+        }
+    }
+
+    fn key(self) -> &'static str {
+        match self {
+            Phase::Parse => "parse",
+            Phase::Part1 => "part1",
+            Phase::Part2 => "part2",
+        }
+    }
+}
+
+impl Display for Phase {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Phase::Parse => write!(f, "Parse"),
+            Phase::Part1 => write!(f, "Part 1"),
+            Phase::Part2 => write!(f, "Part 2"),
+        }
+    }
+}
+
+impl FromStr for Phase {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Phase::ALL
+            .into_iter()
+            .find(|phase| phase.key() == s)
+            .ok_or_else(|| format!("unknown phase '{}'", s))
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        if samples.is_empty() {
+            return Stats::default();
+        }
+
+        let mut sorted = samples.to_vec();
+        sorted.sort_unstable();
+
+        let n = sorted.len();
+        let median = if n.is_multiple_of(2) {
+            (sorted[n / 2 - 1] + sorted[n / 2]) / 2
+        } else {
+            sorted[n / 2]
+        };
+
+        let nanos = sorted
+            .iter()
+            .map(|d| d.as_nanos() as f64)
+            .collect::<Vec<_>>();
+        let mean = nanos.iter().sum::<f64>() / n as f64;
+        let variance = nanos.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / n as f64;
+
+        Stats {
+            min: sorted[0],
+            median,
+            mean: Duration::from_nanos(mean.round() as u64),
+            stddev: Duration::from_nanos(variance.sqrt().round() as u64),
+        }
+    }
+
+    /// Relative change of the median compared to `baseline`, in percent.
+    pub fn change(&self, baseline: &Stats) -> Option<f64> {
+        let before = baseline.median.as_nanos() as f64;
+        let after = self.median.as_nanos() as f64;
+
+        (before > 0.0).then(|| (after - before) / before * 100.0)
+    }
+
+    pub fn report(&self, baseline: Option<&Stats>) -> String {
+        let mut report = format!(
+            "min {:.4} ms · median {:.4} ms · mean {:.4} ms · stddev {:.4} ms",
+            as_ms(self.min),
+            as_ms(self.median),
+            as_ms(self.mean),
+            as_ms(self.stddev)
+        );
+
+        if let Some(change) = baseline.and_then(|baseline| self.change(baseline)) {
+            let verdict = if change > NOISE_THRESHOLD {
+                "regression"
+            } else if change < -NOISE_THRESHOLD {
+                "improvement"
+            } else {
+                "no change"
+            };
+            report.push_str(&format!(" ({:+.2}% vs baseline, {})", change, verdict));
+        }
+
+        report
+    }
+}
+
+/// Benchmark results per day and phase, stored as one whitespace separated line
+/// per entry: `day phase min median mean stddev`, with durations in nanoseconds.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Baseline {
+    entries: BTreeMap<(u8, Phase), Stats>,
+}
+
+impl Baseline {
+    pub fn get(&self, day: u8, phase: Phase) -> Option<&Stats> {
+        self.entries.get(&(day, phase))
+    }
+
+    pub fn insert(&mut self, day: u8, phase: Phase, stats: Stats) {
+        self.entries.insert((day, phase), stats);
+    }
+
+    pub fn load(path: &Path) -> io::Result<Self> {
+        fs::read_to_string(path)?.parse()
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        fs::write(path, self.to_string())
+    }
+}
+
+impl Display for Baseline {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "# day phase min median mean stddev (ns)")?;
+        for ((day, phase), stats) in &self.entries {
+            writeln!(
+                f,
+                "{} {} {} {} {} {}",
+                day,
+                phase.key(),
+                stats.min.as_nanos(),
+                stats.median.as_nanos(),
+                stats.mean.as_nanos(),
+                stats.stddev.as_nanos()
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Baseline {
+    type Err = io::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = |line: usize, reason: String| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("line {}: {}", line, reason),
+            )
+        };
+
+        let mut baseline = Baseline::default();
+
+        for (i, line) in s.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let fields = line.split_whitespace().collect::<Vec<_>>();
+            let [day, phase, min, median, mean, stddev] = fields.as_slice() else {
+                return Err(invalid(
+                    i + 1,
+                    format!("expected 6 fields, found {}", fields.len()),
+                ));
+            };
+
+            let day = day
+                .parse()
+                .map_err(|_| invalid(i + 1, format!("invalid day '{}'", day)))?;
+            let phase = phase.parse().map_err(|err| invalid(i + 1, err))?;
+            let nanos = |value: &str| {
+                value
+                    .parse()
+                    .map(Duration::from_nanos)
+                    .map_err(|_| invalid(i + 1, format!("invalid duration '{}'", value)))
+            };
+
+            let stats = Stats {
+                min: nanos(min)?,
+                median: nanos(median)?,
+                mean: nanos(mean)?,
+                stddev: nanos(stddev)?,
+            };
+            baseline.insert(day, phase, stats);
+        }
+
+        Ok(baseline)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats() {
+        let samples = [4, 1, 3, 2].map(Duration::from_millis);
+        let stats = Stats::from_samples(&samples);
+
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_micros(2500));
+        assert_eq!(stats.mean, Duration::from_micros(2500));
+        assert_eq!(stats.stddev.as_micros(), 1118);
+    }
+
+    #[test]
+    fn test_baseline_round_trip() {
+        let mut baseline = Baseline::default();
+        let stats = Stats::from_samples(&[Duration::from_micros(120), Duration::from_micros(80)]);
+        baseline.insert(17, Phase::Part2, stats);
+
+        let parsed = baseline.to_string().parse::<Baseline>().unwrap();
+        assert_eq!(parsed, baseline);
+
+        let faster = Stats {
+            median: stats.median / 2,
+            ..stats
+        };
+        assert_eq!(faster.change(&stats), Some(-50.0));
+    }
+}
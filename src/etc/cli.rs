@@ -0,0 +1,547 @@
+use std::{
+    fmt::{Display, Formatter},
+    path::PathBuf,
+};
+
+use super::{answers::ANSWERS_FILE, input::Source, log::Level, output::Format};
+use crate::years;
+
+pub const USAGE: &str = "\
+Usage: advent_of_code_template [OPTIONS] [DAYS...]
+       advent_of_code_template --list [DAYS...]
+       advent_of_code_template bench [OPTIONS] [BENCH OPTIONS] [DAYS...]
+       advent_of_code_template history [--year <YEAR>] <DAY>
+       advent_of_code_template new [--year <YEAR>] <DAY>
+       advent_of_code_template submit [OPTIONS] <DAY> <PART>
+       advent_of_code_template watch [OPTIONS] <DAY>
+
+Runs the solvers for the selected days, or every day of the year if none are given.
+Days can be listed individually or as ranges, e.g. `3,7,10-14` or `3 7 10-14`.
+
+Options:
+  -y, --year <YEAR>   Event to run [default: the latest registered year]
+  -p, --part <1|2>    Only print the given part
+  -r, --repeat <N>    Run each day N times and report the mean elapsed time
+                      (not with bench, history, submit or watch)
+  -j, --jobs <N>      Solve up to N days in parallel [default: 1]
+                      (not with bench, history, submit or watch)
+  -q, --quiet         Only print the answers, one per line
+  --art               Also print the pixels of answers drawn as letters
+  -v, --verbose       Show the debug output of the solvers, -vv to also show traces
+  -f, --format <FMT>  Output format: text, json or csv [default: text]
+  -e, --example       Use the example input instead of the real input
+  -i, --input <PATH>  Read the input for a single day from PATH
+  --offline           Do not download missing inputs
+  --no-history        Do not record the run in runs.txt
+  --all-inputs        Run each day against real.txt and every file in its inputs/
+                      directory, and print a table with a row per input
+  -c, --check         Compare the answers against the answers file, or against
+                      the sample answers with --example
+  --answers <PATH>    Answers file used by --check [default: answers.toml]
+  -l, --list          List the registered days and their implemented parts
+  -h, --help          Print this help
+
+Bench options:
+  --warmup <N>            Untimed runs before measuring [default: 3]
+  --samples <N>           Timed runs per day [default: 20]
+  --baseline <PATH>       Compare the results against a saved baseline
+  --save-baseline <PATH>  Save the results as a baseline
+
+`history` lists the runs of a day recorded in runs.txt, oldest first, to follow its
+runtime across commits.
+
+`new` generates the module and an empty example input for a day and registers it,
+creating the year if it does not exist yet.
+
+`submit` solves a part on the real input and posts the answer, using the session in
+AOC_SESSION, so it does not accept --example or --input. Submissions are recorded in
+submissions.txt, and answers that are known to be wrong or that arrive during a
+cooldown are not sent.
+
+`watch` reruns the sample tests and the real input of a day whenever its module or
+input files change, and shows how the answers changed since the previous run.";
+
+pub const DAYS: std::ops::RangeInclusive<u8> = 1..=25;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Options {
+    pub year: u16,
+    pub days: Vec<u8>,
+    pub part: Option<u8>,
+    pub repeat: usize,
+    pub jobs: usize,
+    pub quiet: bool,
+    pub art: bool,
+    /// Most verbose level of solver output to show on stderr, if any.
+    pub verbose: Option<Level>,
+    pub format: Format,
+    pub source: Source,
+    pub input: Option<PathBuf>,
+    pub offline: bool,
+    /// Whether the run is appended to the history in `runs.txt`.
+    pub record: bool,
+    pub all_inputs: bool,
+    pub check: bool,
+    pub answers: PathBuf,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        let year = years::latest();
+
+        Self {
+            year: year.number,
+            days: year.days.iter().map(|day| day.number).collect(),
+            part: None,
+            repeat: 1,
+            jobs: 1,
+            quiet: false,
+            art: false,
+            verbose: None,
+            format: Format::Text,
+            source: Source::Real,
+            input: None,
+            offline: false,
+            record: true,
+            all_inputs: false,
+            check: false,
+            answers: PathBuf::from(ANSWERS_FILE),
+        }
+    }
+}
+
+impl Options {
+    pub fn runs_part(&self, part: u8) -> bool {
+        self.part.is_none_or(|p| p == part)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BenchOptions {
+    pub warmup: usize,
+    pub samples: usize,
+    pub baseline: Option<PathBuf>,
+    pub save_baseline: Option<PathBuf>,
+}
+
+impl Default for BenchOptions {
+    fn default() -> Self {
+        Self {
+            warmup: 3,
+            samples: 20,
+            baseline: None,
+            save_baseline: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Command {
+    Run(Options),
+    Bench(Options, BenchOptions),
+    List(Options),
+    New {
+        year: u16,
+        day: u8,
+    },
+    /// Submits the answer of the single day and part selected in the options.
+    Submit(Options),
+    /// Watches the single day selected in the options.
+    Watch(Options),
+    /// Lists the recorded runs of the single day selected in the options.
+    History(Options),
+    Help,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CliError(pub String);
+
+impl Display for CliError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl std::error::Error for CliError {}
+
+macro_rules! cli_error {
+    ($($arg:tt)*) => {
+        CliError(format!($($arg)*))
+    };
+}
+
+pub fn parse<I>(args: I) -> Result<Command, CliError>
+where
+    I: IntoIterator<Item = String>,
+{
+    let mut options = Options::default();
+    let mut bench_options = BenchOptions::default();
+    let mut days = Vec::new();
+    let mut args = args.into_iter().peekable();
+
+    if args.next_if(|arg| arg == "new").is_some() {
+        return parse_new(args);
+    }
+    let bench = args.next_if(|arg| arg == "bench").is_some();
+    let submit = !bench && args.next_if(|arg| arg == "submit").is_some();
+    let watch = !bench && !submit && args.next_if(|arg| arg == "watch").is_some();
+    let history = !bench && !submit && !watch && args.next_if(|arg| arg == "history").is_some();
+    let subcommand = [
+        (bench, "bench"),
+        (submit, "submit"),
+        (watch, "watch"),
+        (history, "history"),
+    ]
+    .into_iter()
+    .find_map(|(given, name)| given.then_some(name));
+    let mut positional = Vec::new();
+    let mut list = false;
+
+    while let Some(arg) = args.next() {
+        let mut value = |name: &str| {
+            args.next()
+                .ok_or_else(|| cli_error!("{} requires a value", name))
+        };
+
+        match arg.as_str() {
+            "-h" | "--help" => return Ok(Command::Help),
+            "-l" | "--list" if !bench && !submit && !watch => list = true,
+            "-q" | "--quiet" => options.quiet = true,
+            "--art" => options.art = true,
+            "-v" | "--verbose" if !bench => {
+                options.verbose = Some(options.verbose.map_or(Level::Debug, |_| Level::Trace))
+            }
+            "-vv" if !bench => options.verbose = Some(Level::Trace),
+            "-e" | "--example" => options.source = Source::Example,
+            "-f" | "--format" => options.format = value(&arg)?.parse().map_err(CliError)?,
+            "-p" | "--part" => options.part = Some(parse_part(&value(&arg)?)?),
+            // Only plain runs time repeated runs and spread days over jobs
+            "-r" | "--repeat" | "-j" | "--jobs" if subcommand.is_some() => {
+                return Err(cli_error!(
+                    "{} cannot be used with {}",
+                    arg,
+                    subcommand.unwrap_or_default()
+                ));
+            }
+            "-r" | "--repeat" => options.repeat = parse_count(&value(&arg)?, 1)?,
+            "-j" | "--jobs" => options.jobs = parse_count(&value(&arg)?, 1)?,
+            "-y" | "--year" => options.year = parse_year(&value(&arg)?)?,
+            "-i" | "--input" => options.input = Some(PathBuf::from(value(&arg)?)),
+            "--offline" => options.offline = true,
+            "--no-history" => options.record = false,
+            "--all-inputs" if !bench && !submit && !watch => options.all_inputs = true,
+            "-c" | "--check" => options.check = true,
+            "--answers" => options.answers = PathBuf::from(value(&arg)?),
+            "--warmup" if bench => bench_options.warmup = parse_count(&value(&arg)?, 0)?,
+            "--samples" if bench => bench_options.samples = parse_count(&value(&arg)?, 1)?,
+            "--baseline" if bench => bench_options.baseline = Some(PathBuf::from(value(&arg)?)),
+            "--save-baseline" if bench => {
+                bench_options.save_baseline = Some(PathBuf::from(value(&arg)?))
+            }
+            flag if flag.starts_with('-') => return Err(cli_error!("unknown option '{}'", flag)),
+            _ if submit || watch || history => positional.push(arg),
+            selection => days.extend(parse_days(selection)?),
+        }
+    }
+
+    if submit {
+        let [day, part] = positional.as_slice() else {
+            return Err(cli_error!("submit requires a day and a part"));
+        };
+        if options.input.is_some() || options.source == Source::Example {
+            return Err(cli_error!(
+                "submit always uses the real input, it cannot be combined with --input or --example"
+            ));
+        }
+        days = vec![parse_day(day)?];
+        options.part = Some(parse_part(part)?);
+    } else if watch || history {
+        let [day] = positional.as_slice() else {
+            let command = if watch { "watch" } else { "history" };
+            return Err(cli_error!("{} requires a single day", command));
+        };
+        days = vec![parse_day(day)?];
+    }
+
+    if days.is_empty() {
+        let year = years::get(options.year).expect("parse_year only accepts registered years");
+        options.days = year.days.iter().map(|day| day.number).collect();
+    } else {
+        days.sort_unstable();
+        days.dedup();
+        options.days = days;
+    }
+
+    if options.input.is_some() && options.days.len() != 1 {
+        return Err(cli_error!(
+            "--input can only be used when running a single day"
+        ));
+    }
+
+    if options.all_inputs
+        && (options.input.is_some()
+            || options.source == Source::Example
+            || options.format != Format::Text)
+    {
+        return Err(cli_error!(
+            "--all-inputs cannot be combined with --input, --example or --format"
+        ));
+    }
+
+    if bench {
+        Ok(Command::Bench(options, bench_options))
+    } else if submit {
+        Ok(Command::Submit(options))
+    } else if watch {
+        Ok(Command::Watch(options))
+    } else if history {
+        Ok(Command::History(options))
+    } else if list {
+        Ok(Command::List(options))
+    } else {
+        Ok(Command::Run(options))
+    }
+}
+
+fn parse_new(mut args: impl Iterator<Item = String>) -> Result<Command, CliError> {
+    let mut year = years::latest().number;
+    let mut day = None;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-h" | "--help" => return Ok(Command::Help),
+            "-y" | "--year" => {
+                let value = args
+                    .next()
+                    .ok_or_else(|| cli_error!("{} requires a value", arg))?;
+                year = match value.parse() {
+                    Ok(year) if year >= 2015 => year,
+                    _ => return Err(cli_error!("invalid year '{}'", value)),
+                };
+            }
+            flag if flag.starts_with('-') => return Err(cli_error!("unknown option '{}'", flag)),
+            _ if day.is_some() => return Err(cli_error!("new takes a single day")),
+            number => day = Some(parse_day(number)?),
+        }
+    }
+
+    let day = day.ok_or_else(|| cli_error!("new requires a day"))?;
+    Ok(Command::New { year, day })
+}
+
+fn parse_year(year: &str) -> Result<u16, CliError> {
+    match year.parse().ok().and_then(years::get) {
+        Some(year) => Ok(year.number),
+        None => {
+            let known = years::YEARS
+                .iter()
+                .map(|year| year.number.to_string())
+                .collect::<Vec<_>>();
+            Err(cli_error!(
+                "unknown year '{}', expected one of {}",
+                year,
+                known.join(", ")
+            ))
+        }
+    }
+}
+
+fn parse_part(part: &str) -> Result<u8, CliError> {
+    match part {
+        "1" => Ok(1),
+        "2" => Ok(2),
+        other => Err(cli_error!("invalid part '{}', expected 1 or 2", other)),
+    }
+}
+
+fn parse_count(count: &str, min: usize) -> Result<usize, CliError> {
+    match count.parse() {
+        Ok(n) if n >= min => Ok(n),
+        _ => Err(cli_error!("invalid count '{}'", count)),
+    }
+}
+
+/// Parses a day selection such as `3,7,10-14`.
+pub fn parse_days(selection: &str) -> Result<Vec<u8>, CliError> {
+    let mut days = Vec::new();
+
+    for item in selection.split(',').filter(|item| !item.is_empty()) {
+        match item.split_once('-') {
+            Some((from, to)) => {
+                let (from, to) = (parse_day(from)?, parse_day(to)?);
+                if from > to {
+                    return Err(cli_error!("invalid day range '{}'", item));
+                }
+                days.extend(from..=to);
+            }
+            None => days.push(parse_day(item)?),
+        }
+    }
+
+    Ok(days)
+}
+
+fn parse_day(day: &str) -> Result<u8, CliError> {
+    match day.trim().parse() {
+        Ok(day) if DAYS.contains(&day) => Ok(day),
+        _ => Err(cli_error!(
+            "invalid day '{}', expected a number from {} to {}",
+            day,
+            DAYS.start(),
+            DAYS.end()
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(args: &[&str]) -> Result<Options, CliError> {
+        match parse(args.iter().map(|arg| arg.to_string()))? {
+            Command::Run(options) => Ok(options),
+            _ => panic!("unexpected command"),
+        }
+    }
+
+    #[test]
+    fn test_day_selection() {
+        assert_eq!(run(&[]).unwrap().days, DAYS.collect::<Vec<_>>());
+        assert_eq!(
+            run(&["3,7,10-14"]).unwrap().days,
+            vec![3, 7, 10, 11, 12, 13, 14]
+        );
+        assert_eq!(run(&["7", "3", "3-4"]).unwrap().days, vec![3, 4, 7]);
+        assert!(run(&["0"]).is_err());
+        assert!(run(&["26"]).is_err());
+        assert!(run(&["5-3"]).is_err());
+        assert!(run(&["abc"]).is_err());
+
+        let options = run(&["--year", "2023"]).unwrap();
+        assert_eq!(options.year, 2023);
+        assert_eq!(options.days, DAYS.collect::<Vec<_>>());
+        assert_eq!(run(&["-y", "2023", "5"]).unwrap().days, vec![5]);
+        assert!(run(&["--year", "2015"]).is_err());
+        assert!(run(&["--year", "next"]).is_err());
+    }
+
+    #[test]
+    fn test_options() {
+        let options = run(&["-p", "2", "--repeat", "10", "-q", "--example", "4"]).unwrap();
+        assert_eq!(options.part, Some(2));
+        assert_eq!(options.repeat, 10);
+        assert!(options.quiet);
+        assert_eq!(options.source, Source::Example);
+
+        let options = run(&["--check", "--answers", "mine.toml"]).unwrap();
+        assert!(options.check);
+        assert!(run(&["--offline"]).unwrap().offline);
+        assert!(run(&[]).unwrap().record);
+        assert!(!run(&["--no-history"]).unwrap().record);
+        assert!(run(&["--art"]).unwrap().art);
+        assert!(run(&["--all-inputs", "21"]).unwrap().all_inputs);
+        assert!(run(&["--all-inputs", "--example"]).is_err());
+        assert!(run(&["--all-inputs", "-f", "json"]).is_err());
+        assert_eq!(options.answers, PathBuf::from("mine.toml"));
+
+        assert_eq!(run(&["-f", "csv"]).unwrap().format, Format::Csv);
+        assert!(run(&["--format", "xml"]).is_err());
+        assert!(run(&["--part", "3"]).is_err());
+        assert!(run(&["--repeat", "0"]).is_err());
+        assert!(run(&["--repeat"]).is_err());
+        assert_eq!(run(&["-j", "4"]).unwrap().jobs, 4);
+        assert!(run(&["--jobs", "0"]).is_err());
+        assert_eq!(
+            parse(["bench", "--jobs", "4"].map(String::from)),
+            Err(CliError("--jobs cannot be used with bench".to_string()))
+        );
+        assert!(parse(["bench", "-r", "3"].map(String::from)).is_err());
+        assert_eq!(run(&["-v"]).unwrap().verbose, Some(Level::Debug));
+        assert_eq!(
+            run(&["-v", "--verbose"]).unwrap().verbose,
+            Some(Level::Trace)
+        );
+        assert_eq!(run(&["-vv"]).unwrap().verbose, Some(Level::Trace));
+        assert!(parse(["bench", "-v"].map(String::from)).is_err());
+        assert!(run(&["--input", "real.txt"]).is_err());
+        assert!(run(&["--unknown"]).is_err());
+        assert_eq!(parse(["--help".to_string()]), Ok(Command::Help));
+        assert!(matches!(
+            parse(["--list", "3-5"].map(String::from)),
+            Ok(Command::List(Options { days, .. })) if days == vec![3, 4, 5]
+        ));
+    }
+
+    #[test]
+    fn test_bench() {
+        let args = [
+            "bench",
+            "--samples",
+            "50",
+            "--save-baseline",
+            "base.txt",
+            "17",
+        ];
+        let command = parse(args.iter().map(|arg| arg.to_string())).unwrap();
+        let Command::Bench(options, bench_options) = command else {
+            panic!("expected bench command");
+        };
+
+        assert_eq!(options.days, vec![17]);
+        assert_eq!(bench_options.samples, 50);
+        assert_eq!(bench_options.warmup, 3);
+        assert_eq!(bench_options.save_baseline, Some(PathBuf::from("base.txt")));
+
+        assert!(run(&["--samples", "50"]).is_err());
+        assert!(parse(["bench", "--samples", "0"].map(String::from)).is_err());
+    }
+
+    #[test]
+    fn test_new() {
+        assert_eq!(
+            parse(["new", "--year", "2024", "3"].map(String::from)),
+            Ok(Command::New { year: 2024, day: 3 })
+        );
+        assert_eq!(
+            parse(["new", "12"].map(String::from)),
+            Ok(Command::New {
+                year: years::latest().number,
+                day: 12
+            })
+        );
+        assert!(parse(["new"].map(String::from)).is_err());
+        assert!(parse(["new", "3", "4"].map(String::from)).is_err());
+        assert!(parse(["new", "26"].map(String::from)).is_err());
+        assert!(parse(["new", "-y", "24", "1"].map(String::from)).is_err());
+    }
+
+    #[test]
+    fn test_submit() {
+        let command = parse(["submit", "--year", "2023", "7", "2"].map(String::from)).unwrap();
+        let Command::Submit(options) = command else {
+            panic!("expected submit command");
+        };
+        assert_eq!(options.days, vec![7]);
+        assert_eq!(options.part, Some(2));
+
+        assert!(parse(["submit", "7"].map(String::from)).is_err());
+        assert!(parse(["submit", "7", "3"].map(String::from)).is_err());
+        assert!(parse(["submit", "7-9", "1"].map(String::from)).is_err());
+        assert!(parse(["submit", "7", "1", "2"].map(String::from)).is_err());
+        assert!(parse(["submit", "-e", "7", "1"].map(String::from)).is_err());
+        assert!(parse(["submit", "7", "1", "--input", "a.txt"].map(String::from)).is_err());
+        assert!(parse(["submit", "-j", "2", "7", "1"].map(String::from)).is_err());
+        assert!(parse(["submit", "7", "1", "--repeat", "3"].map(String::from)).is_err());
+
+        let command = parse(["watch", "17"].map(String::from)).unwrap();
+        assert!(matches!(command, Command::Watch(Options { days, .. }) if days == vec![17]));
+        assert!(parse(["watch", "17", "18"].map(String::from)).is_err());
+        assert!(parse(["watch", "--jobs", "2", "17"].map(String::from)).is_err());
+        assert!(parse(["watch", "-r", "5", "17"].map(String::from)).is_err());
+
+        let command = parse(["history", "-y", "2023", "9"].map(String::from)).unwrap();
+        assert!(matches!(command, Command::History(Options { days, .. }) if days == vec![9]));
+        assert!(parse(["history"].map(String::from)).is_err());
+        assert!(parse(["history", "-r", "2", "9"].map(String::from)).is_err());
+    }
+}
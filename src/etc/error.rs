@@ -0,0 +1,43 @@
+use std::{
+    fmt::{Display, Formatter},
+    path::Path,
+};
+
+use crate::utils::parse::ParseError;
+
+/// Why a day could not produce its answers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SolveError {
+    /// The input is malformed.
+    Parse(ParseError),
+    /// The input parsed, but does not have the properties the solver relies on.
+    Logic(String),
+}
+
+impl SolveError {
+    /// Attaches the path of the input to parse errors, so they can point into the file.
+    pub fn with_path(self, path: &Path) -> Self {
+        match self {
+            SolveError::Parse(err) => SolveError::Parse(err.with_path(path)),
+            err => err,
+        }
+    }
+}
+
+impl From<ParseError> for SolveError {
+    fn from(err: ParseError) -> Self {
+        SolveError::Parse(err)
+    }
+}
+
+impl Display for SolveError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SolveError::Parse(err) if err.location.is_some() => write!(f, "{}", err),
+            SolveError::Parse(err) => write!(f, "parse error: {}", err),
+            SolveError::Logic(reason) => write!(f, "logic error: {}", reason),
+        }
+    }
+}
+
+impl std::error::Error for SolveError {}
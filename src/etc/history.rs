@@ -0,0 +1,253 @@
+use std::{
+    fmt::{Display, Formatter},
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::Path,
+    process::Command,
+    str::FromStr,
+    time::Duration,
+};
+
+pub const RUNS_FILE: &str = "runs.txt";
+
+const HEADER: &str = "# time commit year day input status parse_ns part1_ns part2_ns";
+
+/// How a day fared in a run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    Ok,
+    /// At least one of the parts returned `Solution::None`.
+    Unimplemented,
+    /// At least one answer differs from the known answer.
+    Wrong,
+    /// The input could not be read or the solver returned an error.
+    Failed,
+}
+
+impl Status {
+    fn key(self) -> &'static str {
+        match self {
+            Status::Ok => "ok",
+            Status::Unimplemented => "none",
+            Status::Wrong => "wrong",
+            Status::Failed => "failed",
+        }
+    }
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Status::Ok => write!(f, "OK"),
+            Status::Unimplemented => write!(f, "NOT IMPLEMENTED"),
+            Status::Wrong => write!(f, "WRONG"),
+            Status::Failed => write!(f, "FAILED"),
+        }
+    }
+}
+
+impl FromStr for Status {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        [
+            Status::Ok,
+            Status::Unimplemented,
+            Status::Wrong,
+            Status::Failed,
+        ]
+        .into_iter()
+        .find(|status| status.key() == s)
+        .ok_or_else(|| format!("unknown status '{}'", s))
+    }
+}
+
+/// One day of one run, stored as a whitespace separated line:
+/// `time commit year day input status parse_ns part1_ns part2_ns`, with `time` in seconds
+/// since the Unix epoch.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Run {
+    pub time: u64,
+    /// Short hash of the checked out commit, with a `-dirty` suffix if the tree had changes,
+    /// or `-` outside of a git checkout.
+    pub commit: String,
+    pub year: u16,
+    pub day: u8,
+    /// `real`, `example` or `custom` for an `--input` file.
+    pub input: String,
+    pub status: Status,
+    pub parse: Duration,
+    pub part1: Duration,
+    pub part2: Duration,
+}
+
+impl Display for Run {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} {} {} {} {} {} {} {} {}",
+            self.time,
+            self.commit,
+            self.year,
+            self.day,
+            self.input,
+            self.status.key(),
+            self.parse.as_nanos(),
+            self.part1.as_nanos(),
+            self.part2.as_nanos()
+        )
+    }
+}
+
+impl FromStr for Run {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let fields = s.split_whitespace().collect::<Vec<_>>();
+        let [time, commit, year, day, input, status, parse, part1, part2] = fields.as_slice()
+        else {
+            return Err("expected 9 fields".to_string());
+        };
+        let number = |field: &str| {
+            field
+                .parse::<u64>()
+                .map_err(|_| format!("invalid number '{}'", field))
+        };
+
+        Ok(Run {
+            time: number(time)?,
+            commit: commit.to_string(),
+            year: number(year)? as u16,
+            day: number(day)? as u8,
+            input: input.to_string(),
+            status: status.parse()?,
+            parse: Duration::from_nanos(number(parse)?),
+            part1: Duration::from_nanos(number(part1)?),
+            part2: Duration::from_nanos(number(part2)?),
+        })
+    }
+}
+
+/// Adds `runs` to the end of the history at `path`, creating it if needed.
+pub fn append(path: &Path, runs: &[Run]) -> io::Result<()> {
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    if file.metadata()?.len() == 0 {
+        writeln!(file, "{}", HEADER)?;
+    }
+
+    let lines = runs
+        .iter()
+        .map(|run| format!("{}\n", run))
+        .collect::<String>();
+    file.write_all(lines.as_bytes())
+}
+
+/// Every run recorded at `path`, oldest first.
+pub fn load(path: &Path) -> io::Result<Vec<Run>> {
+    let contents = match fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(err) => return Err(err),
+    };
+
+    contents
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty() && !line.starts_with('#'))
+        .map(|(i, line)| {
+            line.parse().map_err(|err| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("line {}: {}", i + 1, err),
+                )
+            })
+        })
+        .collect()
+}
+
+/// How long ago a run was, given the seconds since then, e.g. `3 h ago`.
+pub fn format_age(seconds: u64) -> String {
+    match seconds {
+        0..=59 => "just now".to_string(),
+        60..=3599 => format!("{} min ago", seconds / 60),
+        3600..=86_399 => format!("{} h ago", seconds / 3600),
+        86_400..=172_799 => "1 day ago".to_string(),
+        _ => format!("{} days ago", seconds / 86_400),
+    }
+}
+
+/// The commit of the working directory, as stored in `Run::commit`.
+pub fn current_commit() -> String {
+    let git = |args: &[&str]| {
+        Command::new("git")
+            .args(args)
+            .output()
+            .ok()
+            .filter(|output| output.status.success())
+            .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+    };
+
+    match git(&["rev-parse", "--short", "HEAD"]) {
+        Some(hash) if !hash.is_empty() => {
+            let dirty = git(&["status", "--porcelain", "--untracked-files=no"])
+                .is_some_and(|status| !status.is_empty());
+            if dirty {
+                format!("{}-dirty", hash)
+            } else {
+                hash
+            }
+        }
+        _ => "-".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_history() {
+        let run = |day, status, part1| Run {
+            time: 1701388800,
+            commit: "2ed885d-dirty".to_string(),
+            year: 2023,
+            day,
+            input: "real".to_string(),
+            status,
+            parse: Duration::from_micros(250),
+            part1: Duration::from_nanos(part1),
+            part2: Duration::ZERO,
+        };
+        let runs = [run(1, Status::Ok, 41_000), run(5, Status::Unimplemented, 7)];
+
+        assert_eq!(
+            runs[0].to_string(),
+            "1701388800 2ed885d-dirty 2023 1 real ok 250000 41000 0"
+        );
+
+        let path = std::env::temp_dir().join(format!("aoc-runs-{}.txt", std::process::id()));
+        append(&path, &runs[..1]).unwrap();
+        append(&path, &runs[1..]).unwrap();
+
+        let contents = fs::read_to_string(&path).unwrap();
+        assert_eq!(contents.matches(HEADER).count(), 1);
+        assert_eq!(load(&path).unwrap(), runs);
+
+        let ages = [0, 59, 60, 7_200, 86_400, 3 * 86_400 + 5].map(format_age);
+        assert_eq!(
+            ages,
+            [
+                "just now",
+                "just now",
+                "1 min ago",
+                "2 h ago",
+                "1 day ago",
+                "3 days ago"
+            ]
+        );
+
+        fs::write(&path, "1 abc 2023 1 real maybe 1 2 3\n").unwrap();
+        assert!(load(&path).is_err());
+        fs::remove_file(&path).unwrap();
+    }
+}
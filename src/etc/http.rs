@@ -0,0 +1,418 @@
+use std::{
+    io::{self, Read, Write},
+    net::TcpStream,
+    process::{Command, Stdio},
+    time::Duration,
+};
+
+pub const USER_AGENT: &str = "advent_of_code_template";
+
+const TIMEOUT: Duration = Duration::from_secs(30);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Request {
+    pub url: String,
+    /// Value of the `session` cookie, if the request is authenticated.
+    pub session: Option<String>,
+    /// Fields of an `application/x-www-form-urlencoded` body. A request with a form is a POST.
+    pub form: Vec<(String, String)>,
+}
+
+impl Request {
+    pub fn get(url: impl Into<String>) -> Self {
+        Self {
+            url: url.into(),
+            session: None,
+            form: Vec::new(),
+        }
+    }
+
+    pub fn with_session(self, session: impl Into<String>) -> Self {
+        Self {
+            session: Some(session.into()),
+            ..self
+        }
+    }
+
+    pub fn with_form(self, form: &[(&str, &str)]) -> Self {
+        Self {
+            form: form
+                .iter()
+                .map(|(key, value)| (key.to_string(), value.to_string()))
+                .collect(),
+            ..self
+        }
+    }
+
+    pub fn method(&self) -> &'static str {
+        if self.form.is_empty() {
+            "GET"
+        } else {
+            "POST"
+        }
+    }
+
+    fn body(&self) -> String {
+        self.form
+            .iter()
+            .map(|(key, value)| format!("{}={}", url_encode(key), url_encode(value)))
+            .collect::<Vec<_>>()
+            .join("&")
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+impl Response {
+    pub fn is_success(&self) -> bool {
+        (200..300).contains(&self.status)
+    }
+}
+
+/// Sends requests to the Advent of Code server, or to whatever stands in for it.
+pub trait Fetcher {
+    fn send(&self, request: &Request) -> io::Result<Response>;
+}
+
+/// The default `Fetcher`. Plain `http://` URLs are spoken to directly over TCP, and since the
+/// standard library has no TLS, `https://` URLs go through the `curl` binary.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct HttpFetcher;
+
+impl Fetcher for HttpFetcher {
+    fn send(&self, request: &Request) -> io::Result<Response> {
+        if let Some(rest) = request.url.strip_prefix("http://") {
+            send_plain(request, rest)
+        } else if request.url.starts_with("https://") {
+            send_curl(request)
+        } else {
+            Err(invalid_data(format!("unsupported URL '{}'", request.url)))
+        }
+    }
+}
+
+fn send_plain(request: &Request, url: &str) -> io::Result<Response> {
+    let (host, path) = match url.find('/') {
+        Some(i) => url.split_at(i),
+        None => (url, "/"),
+    };
+    let address = if host.contains(':') {
+        host.to_string()
+    } else {
+        format!("{}:80", host)
+    };
+
+    let mut stream = TcpStream::connect(address)?;
+    stream.set_read_timeout(Some(TIMEOUT))?;
+
+    let mut head = format!(
+        "{} {} HTTP/1.1\r\nHost: {}\r\nUser-Agent: {}\r\nConnection: close\r\n",
+        request.method(),
+        path,
+        host,
+        USER_AGENT
+    );
+    if let Some(session) = &request.session {
+        head.push_str(&format!("Cookie: session={}\r\n", session));
+    }
+
+    let body = request.body();
+    if !request.form.is_empty() {
+        head.push_str("Content-Type: application/x-www-form-urlencoded\r\n");
+        head.push_str(&format!("Content-Length: {}\r\n", body.len()));
+    }
+    head.push_str("\r\n");
+
+    stream.write_all(head.as_bytes())?;
+    stream.write_all(body.as_bytes())?;
+
+    let mut raw = Vec::new();
+    stream.read_to_end(&mut raw)?;
+    parse_response(&raw)
+}
+
+fn parse_response(raw: &[u8]) -> io::Result<Response> {
+    let (head, body) =
+        split_bytes(raw, b"\r\n\r\n").ok_or_else(|| invalid_data("truncated HTTP response"))?;
+    let head = String::from_utf8_lossy(head);
+    let mut lines = head.lines();
+
+    let status = lines
+        .next()
+        .and_then(|line| line.split_whitespace().nth(1))
+        .and_then(|status| status.parse().ok())
+        .ok_or_else(|| invalid_data("invalid HTTP status line"))?;
+
+    let chunked = lines.any(|line| {
+        line.split_once(':').is_some_and(|(name, value)| {
+            name.eq_ignore_ascii_case("transfer-encoding") && value.trim() == "chunked"
+        })
+    });
+
+    // Chunks are counted in bytes and can split a character, so only the whole body is decoded
+    let body = if chunked {
+        decode_chunked(body)?
+    } else {
+        body.to_vec()
+    };
+
+    Ok(Response {
+        status,
+        body: String::from_utf8_lossy(&body).into_owned(),
+    })
+}
+
+/// Splits `bytes` around the first `separator`, like `str::split_once`.
+fn split_bytes<'a>(bytes: &'a [u8], separator: &[u8]) -> Option<(&'a [u8], &'a [u8])> {
+    let at = bytes
+        .windows(separator.len())
+        .position(|window| window == separator)?;
+    Some((&bytes[..at], &bytes[at + separator.len()..]))
+}
+
+/// Each chunk is its size in hex and a CRLF, then that many bytes and another CRLF, and a chunk
+/// of size 0 ends the body.
+fn decode_chunked(mut body: &[u8]) -> io::Result<Vec<u8>> {
+    let mut decoded = Vec::new();
+
+    loop {
+        let (size, rest) =
+            split_bytes(body, b"\r\n").ok_or_else(|| invalid_data("truncated chunk"))?;
+        let size = size.split(|&b| b == b';').next().unwrap_or_default();
+        let size = std::str::from_utf8(size)
+            .ok()
+            .and_then(|size| usize::from_str_radix(size.trim(), 16).ok())
+            .ok_or_else(|| invalid_data("invalid chunk size"))?;
+        if size == 0 {
+            return Ok(decoded);
+        }
+
+        let chunk = rest
+            .get(..size)
+            .ok_or_else(|| invalid_data("truncated chunk"))?;
+        decoded.extend_from_slice(chunk);
+        body = rest[size..]
+            .strip_prefix(b"\r\n")
+            .ok_or_else(|| invalid_data("missing CRLF after chunk"))?;
+    }
+}
+
+fn send_curl(request: &Request) -> io::Result<Response> {
+    let mut command = Command::new("curl");
+    command
+        .args(["--silent", "--show-error", "--max-time", "30"])
+        .args(["--user-agent", USER_AGENT])
+        .args(["--write-out", "\n%{http_code}"])
+        // Headers are read from stdin so the session does not show up in the process list
+        .args(["--header", "@-"]);
+    for (key, value) in &request.form {
+        command.args(["--data-urlencode", &format!("{}={}", key, value)]);
+    }
+    command
+        .arg(&request.url)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+
+    let mut child = command.spawn()?;
+    let mut stdin = child.stdin.take().unwrap();
+    if let Some(session) = &request.session {
+        writeln!(stdin, "Cookie: session={}", session)?;
+    }
+    drop(stdin);
+
+    let output = child.wait_with_output()?;
+    if !output.status.success() {
+        return Err(io::Error::other(format!(
+            "curl failed: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        )));
+    }
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let (body, status) = stdout
+        .rsplit_once('\n')
+        .ok_or_else(|| invalid_data("missing status from curl"))?;
+    let status = status
+        .trim()
+        .parse()
+        .map_err(|_| invalid_data("invalid status from curl"))?;
+
+    Ok(Response {
+        status,
+        body: body.to_string(),
+    })
+}
+
+fn url_encode(s: &str) -> String {
+    s.bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                (b as char).to_string()
+            }
+            b' ' => "+".to_string(),
+            b => format!("%{:02X}", b),
+        })
+        .collect()
+}
+
+fn invalid_data(message: impl Into<String>) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message.into())
+}
+
+/// A local stand-in for the Advent of Code server, so the HTTP clients can be tested offline.
+#[cfg(test)]
+pub mod stub {
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        sync::{Arc, Mutex},
+        thread,
+    };
+
+    /// A request as received by the stub: method, path, cookie and body.
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct Received {
+        pub method: String,
+        pub path: String,
+        pub cookie: Option<String>,
+        pub body: String,
+    }
+
+    pub struct StubServer {
+        pub url: String,
+        received: Arc<Mutex<Vec<Received>>>,
+    }
+
+    impl StubServer {
+        /// Serves requests on a free local port, answering each one with `handler`.
+        pub fn start<H>(handler: H) -> Self
+        where
+            H: Fn(&Received) -> (u16, String) + Send + 'static,
+        {
+            let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+            let url = format!("http://{}", listener.local_addr().unwrap());
+            let received = Arc::new(Mutex::new(Vec::new()));
+
+            let log = Arc::clone(&received);
+            thread::spawn(move || {
+                for mut stream in listener.incoming().map_while(Result::ok) {
+                    let Some(request) = read_request(&mut stream) else {
+                        continue;
+                    };
+                    let (status, body) = handler(&request);
+                    log.lock().unwrap().push(request);
+
+                    let _ = write!(
+                        stream,
+                        "HTTP/1.1 {} Stub\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                        status,
+                        body.len(),
+                        body
+                    );
+                }
+            });
+
+            Self { url, received }
+        }
+
+        pub fn received(&self) -> Vec<Received> {
+            self.received.lock().unwrap().clone()
+        }
+    }
+
+    fn read_request(stream: &mut std::net::TcpStream) -> Option<Received> {
+        let mut reader = BufReader::new(stream);
+        let mut line = String::new();
+        reader.read_line(&mut line).ok()?;
+        let mut parts = line.split_whitespace();
+        let (method, path) = (parts.next()?.to_string(), parts.next()?.to_string());
+
+        let (mut cookie, mut length) = (None, 0);
+        loop {
+            let mut header = String::new();
+            reader.read_line(&mut header).ok()?;
+            let header = header.trim_end();
+            if header.is_empty() {
+                break;
+            }
+
+            let (name, value) = header.split_once(':')?;
+            match name.to_ascii_lowercase().as_str() {
+                "cookie" => cookie = Some(value.trim().to_string()),
+                "content-length" => length = value.trim().parse().ok()?,
+                _ => {}
+            }
+        }
+
+        let mut body = vec![0; length];
+        reader.read_exact(&mut body).ok()?;
+
+        Some(Received {
+            method,
+            path,
+            cookie,
+            body: String::from_utf8(body).ok()?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{stub::StubServer, *};
+
+    #[test]
+    fn test_stub_round_trip() {
+        let server = StubServer::start(|request| match request.path.as_str() {
+            "/2023/day/1/input" => (200, "1abc2\n".to_string()),
+            _ => (404, "Not Found".to_string()),
+        });
+
+        let request = Request::get(format!("{}/2023/day/1/input", server.url)).with_session("abc");
+        let response = HttpFetcher.send(&request).unwrap();
+        assert_eq!(
+            response,
+            Response {
+                status: 200,
+                body: "1abc2\n".to_string()
+            }
+        );
+
+        let request = Request::get(format!("{}/2023/day/1/answer", server.url))
+            .with_form(&[("level", "1"), ("answer", "a b&c")]);
+        assert_eq!(HttpFetcher.send(&request).unwrap().status, 404);
+
+        let received = server.received();
+        assert_eq!(received[0].method, "GET");
+        assert_eq!(received[0].cookie.as_deref(), Some("session=abc"));
+        assert_eq!(received[1].method, "POST");
+        assert_eq!(received[1].body, "level=1&answer=a+b%26c");
+    }
+
+    #[test]
+    fn test_chunked() {
+        let head = b"HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\n\r\n";
+        let response = |body: &[u8]| parse_response(&[head, body].concat());
+
+        assert_eq!(
+            response(b"4\r\nWiki\r\n5\r\npedia\r\n0\r\n\r\n")
+                .unwrap()
+                .body,
+            "Wikipedia"
+        );
+        // A character split over two chunks, and a chunk that starts with a CRLF of its own
+        assert_eq!(
+            response(b"2\r\nd\xc3\r\n2\r\n\xa9j\r\n3\r\n\r\nX\r\n0\r\n\r\n")
+                .unwrap()
+                .body,
+            "d\u{e9}j\r\nX"
+        );
+        assert!(response(b"4\r\nWikipedia\r\n0\r\n\r\n").is_err());
+        assert!(response(b"4\r\nWi").is_err());
+        assert!(parse_response(b"HTTP/1.1 200 OK\r\n").is_err());
+    }
+}
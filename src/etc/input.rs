@@ -0,0 +1,251 @@
+use std::{
+    env,
+    fmt::{Display, Formatter},
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use super::http::{Fetcher, HttpFetcher, Request};
+
+pub const INPUT_DIR: &str = "input";
+/// Directory next to `real.txt` with more real inputs of the same day, e.g. other accounts'.
+pub const EXTRA_INPUTS_DIR: &str = "inputs";
+
+/// Environment variable holding the session cookie used to download inputs.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+/// Environment variable that points the downloads at another server, e.g. a local stand-in.
+pub const URL_VAR: &str = "AOC_URL";
+pub const DEFAULT_URL: &str = "https://adventofcode.com";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Source {
+    #[default]
+    Real,
+    Example,
+}
+
+#[derive(Debug)]
+pub struct InputError {
+    pub path: PathBuf,
+    pub error: io::Error,
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "could not read {}: {}", self.path.display(), self.error)
+    }
+}
+
+impl std::error::Error for InputError {}
+
+pub fn day_dir(year: u16, day: u8) -> PathBuf {
+    day_dir_in(Path::new(INPUT_DIR), year, day)
+}
+
+fn day_dir_in(root: &Path, year: u16, day: u8) -> PathBuf {
+    root.join(year.to_string()).join(format!("day{:02}", day))
+}
+
+pub fn path(year: u16, day: u8, source: Source, part: Option<u8>) -> PathBuf {
+    let dir = day_dir(year, day);
+
+    match source {
+        Source::Real => dir.join("real.txt"),
+        Source::Example => {
+            // Some days have a separate example per part, e.g. test_p1.txt and test_p2.txt
+            let candidates = part
+                .map(|part| format!("test_p{}.txt", part))
+                .into_iter()
+                .chain(["test.txt".to_string(), "test_p1.txt".to_string()]);
+
+            candidates
+                .map(|name| dir.join(name))
+                .find(|path| path.exists())
+                .unwrap_or_else(|| dir.join("test.txt"))
+        }
+    }
+}
+
+/// Whether the input for `day` comes as a separate file per part, such as the
+/// `test_p1.txt` and `test_p2.txt` examples of day 1.
+pub fn is_split(year: u16, day: u8, source: Source) -> bool {
+    let dir = day_dir(year, day);
+
+    source == Source::Example
+        && !dir.join("test.txt").exists()
+        && dir.join("test_p1.txt").exists()
+        && dir.join("test_p2.txt").exists()
+}
+
+/// Every real input of a day: `real.txt` if it exists, then the `.txt` files in its `inputs/`
+/// directory by name. The expected answers of an extra input can be stored next to it, in a
+/// file with the same name and the `.answers` extension.
+pub fn all_inputs(year: u16, day: u8) -> io::Result<Vec<PathBuf>> {
+    let dir = day_dir(year, day);
+    let real = dir.join("real.txt");
+
+    let mut extra = match fs::read_dir(dir.join(EXTRA_INPUTS_DIR)) {
+        Ok(entries) => entries
+            .map(|entry| entry.map(|entry| entry.path()))
+            .collect::<io::Result<Vec<_>>>()?,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Vec::new(),
+        Err(err) => return Err(err),
+    };
+    extra.retain(|path| path.extension().is_some_and(|ext| ext == "txt"));
+    extra.sort();
+
+    Ok(real
+        .exists()
+        .then_some(real)
+        .into_iter()
+        .chain(extra)
+        .collect())
+}
+
+pub fn load(year: u16, day: u8, source: Source, part: Option<u8>) -> Result<String, InputError> {
+    read(&path(year, day, source, part))
+}
+
+pub fn read(path: &Path) -> Result<String, InputError> {
+    fs::read_to_string(path).map_err(|error| InputError {
+        path: path.to_owned(),
+        error,
+    })
+}
+
+/// The session cookie in `$AOC_SESSION`, if any.
+pub fn session() -> Option<String> {
+    env::var(SESSION_VAR)
+        .ok()
+        .map(|session| session.trim().to_string())
+        .filter(|session| !session.is_empty())
+}
+
+/// The server in `$AOC_URL`, or adventofcode.com.
+pub fn server_url() -> String {
+    env::var(URL_VAR).unwrap_or_else(|_| DEFAULT_URL.to_string())
+}
+
+/// Real inputs, cached as `input/YYYY/dayNN/real.txt` and downloaded on first use.
+pub struct InputCache {
+    dir: PathBuf,
+    url: String,
+    session: Option<String>,
+    fetcher: Box<dyn Fetcher>,
+}
+
+impl InputCache {
+    pub fn new(dir: impl Into<PathBuf>, url: impl Into<String>, fetcher: Box<dyn Fetcher>) -> Self {
+        Self {
+            dir: dir.into(),
+            url: url.into(),
+            session: None,
+            fetcher,
+        }
+    }
+
+    /// The cache in `input/`, downloading from `$AOC_URL` (or adventofcode.com) with the
+    /// session in `$AOC_SESSION`. Without a session, missing inputs are not downloaded.
+    pub fn from_env() -> Self {
+        let cache = Self::new(INPUT_DIR, server_url(), Box::new(HttpFetcher));
+
+        match session() {
+            Some(session) => cache.with_session(session),
+            None => cache,
+        }
+    }
+
+    pub fn with_session(self, session: impl Into<String>) -> Self {
+        Self {
+            session: Some(session.into()),
+            ..self
+        }
+    }
+
+    /// Returns the path of the real input, downloading it into the cache if it is missing.
+    pub fn real_input(&self, year: u16, day: u8) -> Result<PathBuf, InputError> {
+        let path = day_dir_in(&self.dir, year, day).join("real.txt");
+        if path.exists() {
+            return Ok(path);
+        }
+
+        let error = |error| InputError {
+            path: path.clone(),
+            error,
+        };
+
+        let Some(session) = &self.session else {
+            return Err(error(io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("not found, set {} to download it", SESSION_VAR),
+            )));
+        };
+
+        let url = format!(
+            "{}/{}/day/{}/input",
+            self.url.trim_end_matches('/'),
+            year,
+            day
+        );
+        let response = self
+            .fetcher
+            .send(&Request::get(&url).with_session(session))
+            .map_err(error)?;
+
+        if !response.is_success() {
+            let reason = response.body.lines().next().unwrap_or("").trim();
+            return Err(error(io::Error::other(format!(
+                "downloading {} failed with status {}: {}",
+                url, response.status, reason
+            ))));
+        }
+
+        fs::create_dir_all(path.parent().unwrap()).map_err(error)?;
+        fs::write(&path, response.body).map_err(error)?;
+        Ok(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::etc::http::stub::StubServer;
+
+    #[test]
+    fn test_cache() {
+        let dir = env::temp_dir().join(format!("aoc-input-cache-{}", std::process::id()));
+        let server = StubServer::start(|request| match request.cookie.as_deref() {
+            Some("session=secret") if request.path == "/2023/day/5/input" => {
+                (200, "seeds: 79 14 55 13\n".to_string())
+            }
+            Some("session=secret") => (404, "Not Found".to_string()),
+            _ => (
+                400,
+                "Puzzle inputs differ by user.  Please log in.".to_string(),
+            ),
+        });
+
+        let cache = InputCache::new(&dir, &server.url, Box::new(HttpFetcher));
+        let err = cache.real_input(2023, 5).unwrap_err();
+        assert_eq!(err.error.kind(), io::ErrorKind::NotFound);
+        assert!(server.received().is_empty());
+
+        let cache = cache.with_session("secret");
+        let path = cache.real_input(2023, 5).unwrap();
+        assert_eq!(path, dir.join("2023/day05/real.txt"));
+        assert_eq!(fs::read_to_string(&path).unwrap(), "seeds: 79 14 55 13\n");
+
+        // The second lookup is served from the cache
+        assert_eq!(cache.real_input(2023, 5).unwrap(), path);
+        assert_eq!(server.received().len(), 1);
+
+        let err = cache.real_input(2023, 6).unwrap_err();
+        assert!(err.to_string().contains("failed with status 404"));
+        assert!(!dir.join("2023/day06/real.txt").exists());
+
+        let cache = InputCache::new(&dir, &server.url, Box::new(HttpFetcher)).with_session("old");
+        assert!(cache.real_input(2023, 7).is_err());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
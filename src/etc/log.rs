@@ -0,0 +1,118 @@
+use std::{
+    cell::Cell,
+    fmt::{self, Display, Formatter},
+    io::{self, Write},
+};
+
+/// How much debug output a solver shows. `-v` enables `Debug`, `-vv` also enables `Trace`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    Debug,
+    Trace,
+}
+
+impl Display for Level {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Level::Debug => write!(f, "debug"),
+            Level::Trace => write!(f, "trace"),
+        }
+    }
+}
+
+thread_local! {
+    /// The day being solved on this thread and the most verbose level it may log at, if any.
+    static CURRENT: Cell<Option<(u8, Level)>> = const { Cell::new(None) };
+}
+
+/// Whether messages at `level` are shown. The `debug!` and `trace!` macros check this before
+/// formatting anything, so a disabled message costs a thread-local read.
+#[inline]
+pub fn enabled(level: Level) -> bool {
+    CURRENT.with(|current| current.get().is_some_and(|(_, max)| level <= max))
+}
+
+/// Runs `f` with the messages of `day` shown up to `level`, or none if `level` is `None`.
+pub fn with_level<T>(day: u8, level: Option<Level>, f: impl FnOnce() -> T) -> T {
+    /// Restores the previous level, even if `f` panics.
+    struct Restore(Option<(u8, Level)>);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            CURRENT.set(self.0);
+        }
+    }
+
+    let _restore = Restore(CURRENT.replace(level.map(|level| (day, level))));
+    f()
+}
+
+/// Writes a message to stderr, prefixing each of its lines with the day and level.
+#[doc(hidden)]
+pub fn emit(level: Level, args: fmt::Arguments) {
+    if let Some((day, _)) = CURRENT.get() {
+        let _ = io::stderr()
+            .lock()
+            .write_all(format_message(day, level, args).as_bytes());
+    }
+}
+
+fn format_message(day: u8, level: Level, args: fmt::Arguments) -> String {
+    args.to_string()
+        .lines()
+        .map(|line| format!("[day {:02} {}] {}\n", day, level, line))
+        .collect()
+}
+
+/// Logs a message for the current day when running with `-v`. Takes `format!` arguments,
+/// which are not evaluated unless the message is shown.
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)*) => {
+        if $crate::etc::log::enabled($crate::etc::log::Level::Debug) {
+            $crate::etc::log::emit($crate::etc::log::Level::Debug, format_args!($($arg)*));
+        }
+    };
+}
+
+/// Logs a message for the current day when running with `-vv`, for output too noisy for `-v`
+/// such as whole grids.
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)*) => {
+        if $crate::etc::log::enabled($crate::etc::log::Level::Trace) {
+            $crate::etc::log::emit($crate::etc::log::Level::Trace, format_args!($($arg)*));
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_levels() {
+        assert!(!enabled(Level::Debug));
+
+        with_level(5, Some(Level::Debug), || {
+            assert!(enabled(Level::Debug));
+            assert!(!enabled(Level::Trace));
+
+            with_level(6, None, || assert!(!enabled(Level::Debug)));
+            assert!(enabled(Level::Debug));
+        });
+        assert!(!enabled(Level::Debug));
+
+        let mut formatted = false;
+        crate::trace!("{}", {
+            formatted = true;
+            "skipped"
+        });
+        assert!(!formatted);
+
+        assert_eq!(
+            format_message(5, Level::Trace, format_args!("..#\n#..")),
+            "[day 05 trace] ..#\n[day 05 trace] #..\n"
+        );
+    }
+}
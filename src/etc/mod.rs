@@ -1,3 +1,20 @@
+pub mod alloc;
+pub mod answers;
+pub mod bench;
+pub mod cli;
+pub mod error;
+pub mod history;
+pub mod http;
+pub mod input;
+pub mod log;
+pub mod output;
+pub mod runner;
+pub mod scaffold;
 pub mod solution;
+pub mod submit;
+pub mod timer;
+pub mod watch;
 
+pub use error::SolveError;
 pub use solution::Solution;
+pub use timer::Timer;
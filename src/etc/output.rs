@@ -0,0 +1,209 @@
+use std::{fmt::Write, str::FromStr, time::Duration};
+
+use super::{answers::Verdict, Solution};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Format {
+    #[default]
+    Text,
+    Json,
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => Err(format!(
+                "invalid format '{}', expected text, json or csv",
+                s
+            )),
+        }
+    }
+}
+
+/// One answer of a run, as emitted by the machine-readable formats.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Record {
+    pub day: u8,
+    pub part: u8,
+    pub solution: Solution,
+    pub parse: Duration,
+    pub elapsed: Duration,
+    /// Outcome of `--check`, if the answers were checked.
+    pub verdict: Option<Verdict>,
+}
+
+pub fn to_json(records: &[Record]) -> String {
+    let mut json = String::from("[\n");
+
+    for (i, record) in records.iter().enumerate() {
+        let value = match &record.solution {
+            Solution::None => "null".to_string(),
+            Solution::Str(s) => json_string(s),
+            Solution::Grid(_) => json_string(&record.solution.to_string()),
+            number => number.to_string(),
+        };
+
+        write!(
+            json,
+            "  {{\"day\": {}, \"part\": {}, \"type\": \"{}\", \"value\": {}, \"parse_ns\": {}, \"elapsed_ns\": {}",
+            record.day,
+            record.part,
+            record.solution.kind(),
+            value,
+            record.parse.as_nanos(),
+            record.elapsed.as_nanos()
+        )
+        .unwrap();
+
+        if let Some(verdict) = &record.verdict {
+            write!(json, ", \"status\": \"{}\"", verdict).unwrap();
+            if let Verdict::Wrong(expected) = verdict {
+                write!(json, ", \"expected\": {}", json_string(expected)).unwrap();
+            }
+        }
+        json.push('}');
+
+        json.push_str(if i + 1 < records.len() { ",\n" } else { "\n" });
+    }
+
+    json.push(']');
+    json
+}
+
+pub fn to_csv(records: &[Record]) -> String {
+    let checked = records.iter().any(|record| record.verdict.is_some());
+    let mut csv = String::from("day,part,type,value,parse_ns,elapsed_ns");
+    csv.push_str(if checked { ",status\n" } else { "\n" });
+
+    for record in records {
+        let value = match &record.solution {
+            Solution::None => String::new(),
+            solution => csv_field(&solution.to_string()),
+        };
+
+        write!(
+            csv,
+            "{},{},{},{},{},{}",
+            record.day,
+            record.part,
+            record.solution.kind(),
+            value,
+            record.parse.as_nanos(),
+            record.elapsed.as_nanos()
+        )
+        .unwrap();
+
+        if checked {
+            let status = record.verdict.as_ref().unwrap_or(&Verdict::Unknown);
+            write!(csv, ",{}", status).unwrap();
+        }
+        csv.push('\n');
+    }
+
+    csv
+}
+
+fn json_string(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len() + 2);
+    escaped.push('"');
+
+    for c in s.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if c.is_control() => write!(escaped, "\\u{:04x}", c as u32).unwrap(),
+            c => escaped.push(c),
+        }
+    }
+
+    escaped.push('"');
+    escaped
+}
+
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn records() -> Vec<Record> {
+        vec![
+            Record {
+                day: 1,
+                part: 1,
+                solution: Solution::U32(142),
+                parse: Duration::from_nanos(10),
+                elapsed: Duration::from_nanos(200),
+                verdict: None,
+            },
+            Record {
+                day: 1,
+                part: 2,
+                solution: Solution::Str("a \"b\", c".to_string()),
+                parse: Duration::from_nanos(10),
+                elapsed: Duration::from_nanos(300),
+                verdict: None,
+            },
+            Record {
+                day: 5,
+                part: 2,
+                solution: Solution::None,
+                parse: Duration::ZERO,
+                elapsed: Duration::ZERO,
+                verdict: None,
+            },
+        ]
+    }
+
+    #[test]
+    fn test_json() {
+        let expected = r#"[
+  {"day": 1, "part": 1, "type": "u32", "value": 142, "parse_ns": 10, "elapsed_ns": 200},
+  {"day": 1, "part": 2, "type": "str", "value": "a \"b\", c", "parse_ns": 10, "elapsed_ns": 300},
+  {"day": 5, "part": 2, "type": "none", "value": null, "parse_ns": 0, "elapsed_ns": 0}
+]"#;
+        assert_eq!(to_json(&records()), expected);
+    }
+
+    #[test]
+    fn test_csv() {
+        let expected = "day,part,type,value,parse_ns,elapsed_ns
+1,1,u32,142,10,200
+1,2,str,\"a \"\"b\"\", c\",10,300
+5,2,none,,0,0
+";
+        assert_eq!(to_csv(&records()), expected);
+    }
+
+    #[test]
+    fn test_verdicts() {
+        let mut records = records();
+        records.truncate(2);
+        records[0].verdict = Some(Verdict::Ok);
+        records[1].verdict = Some(Verdict::Wrong("d".to_string()));
+
+        let json = to_json(&records);
+        assert!(json.contains("\"elapsed_ns\": 200, \"status\": \"OK\"}"));
+        assert!(json.contains("\"status\": \"WRONG\", \"expected\": \"d\"}"));
+
+        let csv = to_csv(&records);
+        assert!(csv.starts_with("day,part,type,value,parse_ns,elapsed_ns,status\n"));
+        assert!(csv.contains("1,1,u32,142,10,200,OK\n"));
+        assert!(csv.ends_with(",10,300,WRONG\n"));
+    }
+}
@@ -0,0 +1,933 @@
+use crate::utils::ocr;
+use crate::years::{self, Day, Solver};
+use crate::{Solution, SolutionPair, SolveError, Timer};
+use std::cell::Cell;
+use std::io;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use super::alloc::{self, format_bytes, AllocStats};
+use super::answers::{Answers, Verdict};
+use super::bench::{Baseline, Phase, Stats};
+use super::cli::{BenchOptions, Options};
+use super::history::{self, Run, Status, RUNS_FILE};
+use super::http::HttpFetcher;
+use super::input::{self, InputCache, InputError, Source};
+use super::log;
+use super::output::{self, Format, Record};
+use super::submit::{self, Client, History, Submission, HISTORY_FILE};
+use super::timer::{as_ms, Timings};
+
+pub fn list(options: &Options) -> bool {
+    for day in options
+        .days
+        .iter()
+        .filter_map(|&day| find_day(options, day))
+    {
+        let parts = match day.implemented {
+            [true, true] => "part 1, part 2",
+            [true, false] => "part 1",
+            [false, true] => "part 2",
+            [false, false] => "not implemented",
+        };
+        println!("Day {:02}: {} [{}]", day.number, day.title, parts);
+    }
+
+    true
+}
+
+pub fn run(options: &Options) -> bool {
+    if options.all_inputs {
+        return run_all_inputs(options);
+    }
+
+    let expected = match options.check.then(|| expected_answers(options)) {
+        Some(Ok(answers)) => Some(answers),
+        Some(Err(err)) => {
+            eprintln!(
+                "error: could not read answers {}: {}",
+                options.answers.display(),
+                err
+            );
+            return false;
+        }
+        None => None,
+    };
+
+    let mut runtime = Timings::default();
+    let mut records = Vec::new();
+    let mut summary = vec![[
+        "Day", "Part 1", "Part 2", "Parse", "Time 1", "Time 2", "Status",
+    ]
+    .map(String::from)];
+    let mut runs = Vec::new();
+    let mut success = true;
+
+    // Finding the commit runs git, which is not worth it for a run that is not recorded
+    let commit = if options.record {
+        history::current_commit()
+    } else {
+        "-".to_string()
+    };
+    let time = submit::now();
+    let input = match (&options.input, options.source) {
+        (Some(_), _) => "custom",
+        (None, Source::Real) => "real",
+        (None, Source::Example) => "example",
+    };
+    let run = |day, status, timings: Timings| Run {
+        time,
+        commit: commit.clone(),
+        year: options.year,
+        day,
+        input: input.to_string(),
+        status,
+        parse: timings.parse,
+        part1: timings.part1,
+        part2: timings.part2,
+    };
+
+    let started = Instant::now();
+    let solved = solve_days(options);
+    let wall_clock = started.elapsed();
+
+    for (&day, solved) in options.days.iter().zip(solved) {
+        let ((p1, p2), timings) = match solved {
+            Ok(solved) => solved,
+            Err(err) => {
+                eprintln!("error: {}", err);
+                success = false;
+
+                summary.push(summary_row(day, None, options, Status::Failed));
+                runs.push(run(day, Status::Failed, Timings::default()));
+                continue;
+            }
+        };
+
+        let results = [(1, p1, timings.part1), (2, p2, timings.part2)]
+            .into_iter()
+            .filter(|(part, _, _)| options.runs_part(*part))
+            .map(|(part, solution, elapsed)| {
+                let verdict = expected
+                    .as_ref()
+                    .map(|expected| expected.check(options.year, day, part, &solution));
+                (part, solution, elapsed, verdict)
+            })
+            .collect::<Vec<_>>();
+
+        let status = if results
+            .iter()
+            .any(|(_, _, _, verdict)| matches!(verdict, Some(Verdict::Wrong(_))))
+        {
+            success = false;
+            Status::Wrong
+        } else if results
+            .iter()
+            .any(|(_, solution, _, _)| *solution == Solution::None)
+        {
+            Status::Unimplemented
+        } else {
+            Status::Ok
+        };
+
+        let answers = results
+            .iter()
+            .map(|(part, solution, _, _)| (*part, solution))
+            .collect::<Vec<_>>();
+        summary.push(summary_row(
+            day,
+            Some((&answers, &timings)),
+            options,
+            status,
+        ));
+        runs.push(run(day, status, timings));
+
+        if options.format != Format::Text {
+            records.extend(
+                results
+                    .into_iter()
+                    .map(|(part, solution, elapsed, verdict)| Record {
+                        day,
+                        part,
+                        solution,
+                        parse: timings.parse,
+                        elapsed,
+                        verdict,
+                    }),
+            );
+        } else if options.quiet {
+            results
+                .iter()
+                .for_each(|(_, answer, _, _)| println!("{}", answer));
+        } else {
+            println!("\n=== Day {:02} ===", day);
+            for (part, answer, _, verdict) in &results {
+                match verdict {
+                    Some(Verdict::Wrong(expected)) => println!(
+                        "  · Part {}: {} [WRONG, expected {}]",
+                        part, answer, expected
+                    ),
+                    Some(verdict) => println!("  · Part {}: {} [{}]", part, answer, verdict),
+                    None => println!("  · Part {}: {}", part, answer),
+                }
+                if let Some(art) = answer.art().filter(|_| options.art) {
+                    art.lines().for_each(|line| println!("      {}", line));
+                }
+            }
+            println!("  · Elapsed: {}", format_timings(&timings));
+            if alloc::enabled() {
+                println!("  · Allocations: {}", format_allocations(&timings));
+            }
+        }
+
+        runtime += timings;
+    }
+
+    if options.record {
+        if let Err(err) = history::append(Path::new(RUNS_FILE), &runs) {
+            eprintln!(
+                "warning: could not record the run in {}: {}",
+                RUNS_FILE, err
+            );
+        }
+    }
+
+    match options.format {
+        Format::Json => println!("{}", output::to_json(&records)),
+        Format::Csv => print!("{}", output::to_csv(&records)),
+        Format::Text if !options.quiet => {
+            println!("\n=== Summary ===");
+            print_table(&summary);
+            println!("Total runtime: {}", format_timings(&runtime));
+            println!(
+                "Wall-clock time: {:.4} ms ({} {})",
+                as_ms(wall_clock),
+                options.jobs,
+                if options.jobs == 1 { "job" } else { "jobs" }
+            );
+        }
+        Format::Text => {}
+    }
+
+    success
+}
+
+/// Lists the runs of the selected day recorded in `runs.txt`, oldest first, so its runtime can
+/// be followed across commits.
+pub fn history(options: &Options) -> bool {
+    let day = options.days[0];
+    let runs = match history::load(Path::new(RUNS_FILE)) {
+        Ok(runs) => runs,
+        Err(err) => {
+            eprintln!("error: could not read {}: {}", RUNS_FILE, err);
+            return false;
+        }
+    };
+
+    let now = submit::now();
+    let mut rows = vec![[
+        "When", "Commit", "Input", "Parse", "Time 1", "Time 2", "Status",
+    ]
+    .map(String::from)];
+    let ms = |elapsed: Duration| format!("{:.4} ms", as_ms(elapsed));
+    for run in runs
+        .into_iter()
+        .filter(|run| run.year == options.year && run.day == day)
+    {
+        rows.push([
+            history::format_age(now.saturating_sub(run.time)),
+            run.commit,
+            run.input,
+            ms(run.parse),
+            ms(run.part1),
+            ms(run.part2),
+            run.status.to_string(),
+        ]);
+    }
+
+    if rows.len() == 1 {
+        println!("No runs of day {:02} recorded in {}", day, RUNS_FILE);
+    } else {
+        println!("\n=== Day {:02} ===", day);
+        print_table(&rows);
+    }
+    true
+}
+
+pub fn bench(options: &Options, bench_options: &BenchOptions) -> bool {
+    let baseline = match &bench_options.baseline {
+        Some(path) => match Baseline::load(path) {
+            Ok(baseline) => Some(baseline),
+            Err(err) => {
+                eprintln!("error: could not read baseline {}: {}", path.display(), err);
+                return false;
+            }
+        },
+        None => None,
+    };
+
+    let mut results = Baseline::default();
+    let mut success = true;
+
+    for &day in &options.days {
+        let Some(func) = find_day(options, day).map(|day| day.solve) else {
+            eprintln!("error: day {:02} has no solver", day);
+            success = false;
+            continue;
+        };
+
+        let inputs = match load_inputs(day, options) {
+            Ok(inputs) => inputs,
+            Err(err) => {
+                eprintln!("error: day {:02}: {}", day, err);
+                success = false;
+                continue;
+            }
+        };
+
+        let runs = (0..bench_options.warmup + bench_options.samples)
+            .map(|_| match catch_panic(|| solve_once(func, &inputs)) {
+                Ok(solved) => solved
+                    .map(|(_, timings)| timings)
+                    .map_err(|err| err.to_string()),
+                Err(message) => Err(format!("panicked: {}", message)),
+            })
+            .collect::<Result<Vec<_>, _>>();
+        let samples = match runs {
+            Ok(runs) => runs[bench_options.warmup..].to_vec(),
+            Err(err) => {
+                eprintln!("error: day {:02}: {}", day, err);
+                success = false;
+                continue;
+            }
+        };
+
+        println!("\n=== Day {:02} ===", day);
+        for phase in Phase::ALL {
+            if phase.part().is_some_and(|part| !options.runs_part(part)) {
+                continue;
+            }
+
+            let durations = samples.iter().map(|t| phase.of(t)).collect::<Vec<_>>();
+            let stats = Stats::from_samples(&durations);
+            let previous = baseline.as_ref().and_then(|b| b.get(day, phase));
+
+            println!(
+                "  · {:<7} {}",
+                format!("{}:", phase),
+                stats.report(previous)
+            );
+            results.insert(day, phase, stats);
+        }
+    }
+
+    if let Some(path) = &bench_options.save_baseline {
+        if let Err(err) = results.save(path) {
+            eprintln!(
+                "error: could not write baseline {}: {}",
+                path.display(),
+                err
+            );
+            success = false;
+        }
+    }
+
+    success
+}
+
+/// Runs every selected day against all of its real inputs and prints a table with a row per
+/// input. A solver that panics on an input is reported in its row instead of stopping the run.
+fn run_all_inputs(options: &Options) -> bool {
+    let answers = match Answers::load(&options.answers) {
+        Ok(answers) => answers,
+        Err(err) => {
+            eprintln!(
+                "error: could not read answers {}: {}",
+                options.answers.display(),
+                err
+            );
+            return false;
+        }
+    };
+
+    let _quiet = QuietPanics::install();
+
+    let mut success = true;
+    for &day in &options.days {
+        let Some(func) = find_day(options, day).map(|day| day.solve) else {
+            eprintln!("error: day {:02} has no solver", day);
+            success = false;
+            continue;
+        };
+
+        let paths = match input::all_inputs(options.year, day) {
+            Ok(paths) if paths.is_empty() => {
+                eprintln!("error: day {:02} has no real inputs", day);
+                success = false;
+                continue;
+            }
+            Ok(paths) => paths,
+            Err(err) => {
+                eprintln!("error: day {:02}: {}", day, err);
+                success = false;
+                continue;
+            }
+        };
+
+        let mut rows = vec![[
+            "Input".to_string(),
+            "Part 1".to_string(),
+            "Part 2".to_string(),
+            "Status".to_string(),
+        ]];
+        for path in paths {
+            let name = path.file_name().unwrap().to_string_lossy().to_string();
+            let (answers, status) = match check_input(func, day, &path, &answers, options) {
+                Ok((answers, status)) => (answers, status),
+                Err(err) => (["-".to_string(), "-".to_string()], err),
+            };
+
+            if status != "OK" && status != "UNKNOWN" {
+                success = false;
+            }
+            let [p1, p2] = answers;
+            rows.push([name, p1, p2, status]);
+        }
+
+        println!("\n=== Day {:02} ===", day);
+        print_table(&rows);
+    }
+
+    success
+}
+
+thread_local! {
+    /// Set while `check_input` solves on this thread, whose panics are reported in the table.
+    static EXPECTED_PANIC: Cell<bool> = const { Cell::new(false) };
+}
+
+type PanicHook = Box<dyn Fn(&panic::PanicHookInfo<'_>) + Sync + Send + 'static>;
+
+/// Keeps the panic hook from printing the panics that `check_input` reports itself, and puts
+/// the previous hook back when dropped. Panics anywhere else are printed as usual.
+struct QuietPanics(Arc<PanicHook>);
+
+impl QuietPanics {
+    fn install() -> Self {
+        let previous = Arc::new(panic::take_hook());
+        let hook = Arc::clone(&previous);
+        panic::set_hook(Box::new(move |info| {
+            if !EXPECTED_PANIC.get() {
+                hook(info);
+            }
+        }));
+        QuietPanics(previous)
+    }
+}
+
+impl Drop for QuietPanics {
+    fn drop(&mut self) {
+        // The hook cannot be changed while unwinding, and ours still forwards other panics
+        if thread::panicking() {
+            return;
+        }
+        let previous = Arc::clone(&self.0);
+        panic::set_hook(Box::new(move |info| previous(info)));
+    }
+}
+
+/// Solves one input of a day and checks the answers, either against the answers file for
+/// `real.txt` or against the `.answers` file next to any other input.
+fn check_input(
+    func: Solver,
+    day: u8,
+    path: &Path,
+    answers: &Answers,
+    options: &Options,
+) -> Result<([String; 2], String), String> {
+    let expected = if path.ends_with("real.txt") {
+        answers.clone()
+    } else {
+        let path = path.with_extension("answers");
+        Answers::load_for_input(&path, options.year, day)
+            .map_err(|err| format!("ERROR: could not read {}: {}", path.display(), err))?
+    };
+
+    let input = DayInput::read(options.part, path.to_path_buf())
+        .map_err(|err| format!("ERROR: {}", err))?;
+    let solve = || log::with_level(day, options.verbose, || solve_once(func, &[input]));
+    EXPECTED_PANIC.set(true);
+    let solved = catch_panic(solve);
+    EXPECTED_PANIC.set(false);
+    let ((p1, p2), _) = solved
+        .map_err(|message| format!("PANIC: {}", message))?
+        .map_err(|err| format!("ERROR: {}", err))?;
+
+    let verdicts = [(1, &p1), (2, &p2)]
+        .into_iter()
+        .filter(|(part, _)| options.runs_part(*part))
+        .map(|(part, solution)| (part, expected.check(options.year, day, part, solution)))
+        .collect::<Vec<_>>();
+
+    let status = match verdicts
+        .iter()
+        .find(|(_, v)| matches!(v, Verdict::Wrong(_)))
+    {
+        Some((part, Verdict::Wrong(expected))) => {
+            format!("WRONG (part {}, expected {})", part, expected)
+        }
+        _ if verdicts.iter().any(|(_, v)| *v == Verdict::Ok) => "OK".to_string(),
+        _ => "UNKNOWN".to_string(),
+    };
+
+    let show = |solution: Solution| match solution {
+        Solution::None => "-".to_string(),
+        solution => solution.to_string(),
+    };
+    Ok(([show(p1), show(p2)], status))
+}
+
+/// Runs `f`, turning a panic into an error with the panic message, so that one broken day
+/// does not take down the whole run.
+fn catch_panic<T>(f: impl FnOnce() -> T) -> Result<T, String> {
+    panic::catch_unwind(AssertUnwindSafe(f)).map_err(|payload| panic_message(&*payload))
+}
+
+fn panic_message(payload: &(dyn std::any::Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message
+            .lines()
+            .map(str::trim)
+            .collect::<Vec<_>>()
+            .join(", ")
+    } else {
+        "unknown panic".to_string()
+    }
+}
+
+fn print_table<const N: usize>(rows: &[[String; N]]) {
+    let mut widths = [0; N];
+    for row in rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    for row in rows {
+        let line = row
+            .iter()
+            .zip(widths)
+            .map(|(cell, width)| format!("{:<width$}", cell, width = width))
+            .collect::<Vec<_>>()
+            .join("  ");
+        println!("  {}", line.trim_end());
+    }
+}
+
+/// Loads and solves every selected day, on `options.jobs` threads. The results are in the
+/// order of `options.days`, whichever day finishes first.
+fn solve_days(options: &Options) -> Vec<Result<(SolutionPair, Timings), String>> {
+    let solve_day = |day: u8| {
+        let func = find_day(options, day)
+            .map(|day| day.solve)
+            .ok_or_else(|| format!("day {:02} has no solver", day))?;
+        let inputs = load_inputs(day, options).map_err(|err| format!("day {:02}: {}", day, err))?;
+
+        catch_panic(|| {
+            log::with_level(day, options.verbose, || {
+                solve_repeatedly(func, &inputs, options.repeat)
+            })
+        })
+        .map_err(|message| format!("day {:02}: panicked: {}", day, message))?
+        .map_err(|err| format!("day {:02}: {}", day, err))
+    };
+
+    let jobs = options.jobs.min(options.days.len());
+    if jobs <= 1 {
+        return options.days.iter().map(|&day| solve_day(day)).collect();
+    }
+
+    let next = AtomicUsize::new(0);
+    let results = Mutex::new((0..options.days.len()).map(|_| None).collect::<Vec<_>>());
+
+    thread::scope(|scope| {
+        for _ in 0..jobs {
+            scope.spawn(|| loop {
+                let i = next.fetch_add(1, Ordering::Relaxed);
+                let Some(&day) = options.days.get(i) else {
+                    break;
+                };
+
+                let solved = solve_day(day);
+                results.lock().unwrap()[i] = Some(solved);
+            });
+        }
+    });
+
+    results
+        .into_inner()
+        .unwrap()
+        .into_iter()
+        .map(|solved| solved.expect("every day is solved by a worker"))
+        .collect()
+}
+
+/// Solves the selected day and part on the real input and submits the answer, unless the
+/// history already shows that it would be wrong or that the server would not accept it yet.
+pub fn submit(options: &Options) -> bool {
+    let (day, part) = (options.days[0], options.part.unwrap_or(1));
+    let Some(func) = find_day(options, day).map(|day| day.solve) else {
+        eprintln!("error: day {:02} has no solver", day);
+        return false;
+    };
+
+    let solved = load_inputs(day, options)
+        .map_err(|err| err.to_string())
+        .and_then(|inputs| solve_once(func, &inputs).map_err(|err| err.to_string()));
+    let answer = match solved {
+        Ok(((p1, p2), _)) => {
+            if part == 1 {
+                p1
+            } else {
+                p2
+            }
+        }
+        Err(err) => {
+            eprintln!("error: day {:02}: {}", day, err);
+            return false;
+        }
+    };
+    if answer == Solution::None {
+        eprintln!("error: day {:02} part {} has no answer", day, part);
+        return false;
+    }
+    if let Solution::Grid(art) = &answer {
+        if ocr::decode(art).is_none() {
+            eprintln!(
+                "error: day {:02} part {}: cannot read the letters, see --art",
+                day, part
+            );
+            return false;
+        }
+    }
+    let answer = answer.to_string();
+
+    let path = Path::new(HISTORY_FILE);
+    let mut history = match History::load(path) {
+        Ok(history) => history,
+        Err(err) => {
+            eprintln!("error: could not read {}: {}", path.display(), err);
+            return false;
+        }
+    };
+
+    if let Err(refusal) = history.check(options.year, day, part, &answer, submit::now()) {
+        eprintln!("error: not submitting: {}", refusal);
+        return false;
+    }
+
+    let Some(session) = input::session() else {
+        eprintln!("error: set {} to submit answers", input::SESSION_VAR);
+        return false;
+    };
+
+    let client = Client::new(input::server_url(), session, Box::new(HttpFetcher));
+    let reply = match client.submit(options.year, day, part, &answer) {
+        Ok(reply) => reply,
+        Err(err) => {
+            eprintln!("error: could not submit {}: {}", answer, err);
+            return false;
+        }
+    };
+
+    history.push(Submission {
+        time: submit::now(),
+        year: options.year,
+        day,
+        part,
+        answer: answer.clone(),
+        reply,
+    });
+    if let Err(err) = history.save(path) {
+        eprintln!("error: could not write {}: {}", path.display(), err);
+    }
+
+    println!(
+        "Day {:02} part {}: {} is {}",
+        day, part, answer, reply.outcome
+    );
+    if !reply.wait.is_zero() {
+        println!("  · Wait {}s before submitting again", reply.wait.as_secs());
+    }
+
+    reply.outcome == submit::Outcome::Correct
+}
+
+/// The example inputs are checked against the sample answers of the registry, and
+/// everything else against the answers file.
+fn expected_answers(options: &Options) -> io::Result<Answers> {
+    if options.source != Source::Example || options.input.is_some() {
+        return Answers::load(&options.answers);
+    }
+
+    let mut answers = Answers::default();
+    for year in years::YEARS {
+        for day in year.days {
+            for (part, sample) in (1..).zip(day.samples) {
+                if let Some(sample) = sample {
+                    answers.insert(year.number, day.number, part, sample);
+                }
+            }
+        }
+    }
+    Ok(answers)
+}
+
+fn find_day(options: &Options, day: u8) -> Option<&'static Day> {
+    years::get(options.year).and_then(|year| year.get(day))
+}
+
+/// An input file, tagged with the part it should be solved for.
+struct DayInput {
+    part: Option<u8>,
+    path: PathBuf,
+    text: String,
+}
+
+impl DayInput {
+    fn read(part: Option<u8>, path: PathBuf) -> Result<Self, InputError> {
+        let text = input::read(&path)?;
+        Ok(Self { part, path, text })
+    }
+}
+
+/// Loads the inputs for a day. Split examples need one run per part, each against its own file,
+/// and missing real inputs are downloaded unless running `--offline`.
+fn load_inputs(day: u8, options: &Options) -> Result<Vec<DayInput>, InputError> {
+    if let Some(path) = &options.input {
+        return Ok(vec![DayInput::read(options.part, path.clone())?]);
+    }
+
+    let parts = match options.part {
+        None if input::is_split(options.year, day, options.source) => vec![Some(1), Some(2)],
+        part => vec![part],
+    };
+
+    parts
+        .into_iter()
+        .map(|part| {
+            let path = match options.source {
+                Source::Real if !options.offline => {
+                    InputCache::from_env().real_input(options.year, day)?
+                }
+                source => input::path(options.year, day, source, part),
+            };
+            DayInput::read(part, path)
+        })
+        .collect()
+}
+
+/// Solves a day `repeat` times, returning the last answers and the mean timings.
+fn solve_repeatedly(
+    func: Solver,
+    inputs: &[DayInput],
+    repeat: usize,
+) -> Result<(SolutionPair, Timings), SolveError> {
+    let mut answers = (Solution::None, Solution::None);
+    let mut timings = Timings::default();
+
+    for _ in 0..repeat {
+        let (solutions, run_timings) = solve_once(func, inputs)?;
+        answers = solutions;
+        timings += run_timings;
+    }
+
+    Ok((answers, timings / repeat as u32))
+}
+
+fn solve_once(func: Solver, inputs: &[DayInput]) -> Result<(SolutionPair, Timings), SolveError> {
+    let mut answers = (Solution::None, Solution::None);
+    let mut timings = Timings::default();
+
+    for input in inputs {
+        let mut timer = Timer::new(input.part);
+        let (p1, p2) = func(&input.text, &mut timer).map_err(|err| err.with_path(&input.path))?;
+        timings += timer.timings();
+
+        if input.part != Some(2) {
+            answers.0 = p1;
+        }
+        if input.part != Some(1) {
+            answers.1 = p2;
+        }
+    }
+
+    Ok((answers, timings))
+}
+
+/// A row of the summary table. Answers of parts that were not run or not solved, and all
+/// the cells of a day that failed, are shown as `-`.
+fn summary_row(
+    day: u8,
+    solved: Option<(&[(u8, &Solution)], &Timings)>,
+    options: &Options,
+    status: Status,
+) -> [String; 7] {
+    let dash = || "-".to_string();
+    let Some((answers, timings)) = solved else {
+        let mut row = [(); 7].map(|_| dash());
+        row[0] = format!("{:02}", day);
+        row[6] = status.to_string();
+        return row;
+    };
+
+    let answer = |part: u8| match answers.iter().find(|(p, _)| *p == part) {
+        Some((_, Solution::None)) | None => dash(),
+        // The picture would break the table, and is printed with the day's answers anyway
+        Some((_, Solution::Grid(art))) if ocr::decode(art).is_none() => "?".to_string(),
+        Some((_, solution)) => solution.to_string(),
+    };
+    let elapsed = |part: u8, elapsed: Duration| {
+        if options.runs_part(part) {
+            format!("{:.4} ms", as_ms(elapsed))
+        } else {
+            dash()
+        }
+    };
+
+    [
+        format!("{:02}", day),
+        answer(1),
+        answer(2),
+        format!("{:.4} ms", as_ms(timings.parse)),
+        elapsed(1, timings.part1),
+        elapsed(2, timings.part2),
+        status.to_string(),
+    ]
+}
+
+fn format_timings(timings: &Timings) -> String {
+    format!(
+        "{:.4} ms (parse: {:.4} ms, part 1: {:.4} ms, part 2: {:.4} ms)",
+        as_ms(timings.total()),
+        as_ms(timings.parse),
+        as_ms(timings.part1),
+        as_ms(timings.part2)
+    )
+}
+
+fn format_allocations(timings: &Timings) -> String {
+    let phase = |name: &str, stats: AllocStats| {
+        format!(
+            "{} {} ({}, peak {})",
+            name,
+            stats.allocations,
+            format_bytes(stats.bytes),
+            format_bytes(stats.peak)
+        )
+    };
+
+    [
+        phase("parse:", timings.allocs.parse),
+        phase("part 1:", timings.allocs.part1),
+        phase("part 2:", timings.allocs.part2),
+    ]
+    .join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_jobs_keep_day_order() {
+        let options = Options {
+            days: vec![1, 2, 3, 4, 6, 7, 9],
+            source: Source::Example,
+            offline: true,
+            ..Options::default()
+        };
+        let answers = |options: &Options| {
+            solve_days(options)
+                .into_iter()
+                .map(|solved| solved.unwrap().0)
+                .collect::<Vec<_>>()
+        };
+
+        let sequential = answers(&options);
+        let parallel = answers(&Options { jobs: 3, ..options });
+        assert_eq!(parallel, sequential);
+        assert_eq!(parallel[4].0, Solution::Usize(288));
+    }
+
+    #[test]
+    fn test_check_input() {
+        let dir = std::env::temp_dir().join(format!("aoc-all-inputs-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let options = Options::default();
+        let year = crate::years::get(2023).unwrap();
+        let check = |day: u8, name: &str, input: &str, expected: &str| {
+            let path = dir.join(name);
+            std::fs::write(&path, input).unwrap();
+            std::fs::write(path.with_extension("answers"), expected).unwrap();
+            let func = year.get(day).unwrap().solve;
+            check_input(func, day, &path, &Answers::default(), &options)
+        };
+
+        let races = "Time:      7  15   30\nDistance:  9  40  200\n";
+        assert_eq!(
+            check(6, "alice.txt", races, "part1 = 288\npart2 = 71503\n"),
+            Ok((["288".to_string(), "71503".to_string()], "OK".to_string()))
+        );
+        assert_eq!(
+            check(6, "bob.txt", races, "part2 = 1\n").unwrap().1,
+            "WRONG (part 2, expected 1)"
+        );
+
+        let garden = include_str!("../../input/2023/day21/test.txt");
+        assert_eq!(
+            check(21, "carol.txt", garden, ""),
+            Err(
+                "ERROR: logic error: 26501365 steps do not end on the edge of a square garden"
+                    .to_string()
+            )
+        );
+
+        fn broken(_: &str, _: &mut Timer) -> Result<SolutionPair, SolveError> {
+            panic!("the solver is broken")
+        }
+        let path = dir.join("dave.txt");
+        std::fs::write(&path, garden).unwrap();
+        std::fs::write(path.with_extension("answers"), "").unwrap();
+        let quiet = QuietPanics::install();
+        let panicked = check_input(broken, 21, &path, &Answers::default(), &options);
+        drop(quiet);
+        assert_eq!(panicked, Err("PANIC: the solver is broken".to_string()));
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_summary_row() {
+        let unreadable = Solution::Grid("#.#\n.#.\n#.#".to_string());
+        let answers = [(1, &Solution::Usize(7)), (2, &unreadable)];
+        let solved = Some((&answers[..], &Timings::default()));
+        let row = summary_row(3, solved, &Options::default(), Status::Ok);
+        assert_eq!(row[..3], ["03", "7", "?"]);
+    }
+
+    #[test]
+    fn test_catch_panic() {
+        assert_eq!(catch_panic(|| 4), Ok(4));
+        assert_eq!(
+            catch_panic(|| -> u8 { panic!("day {:02} is broken", 4) }),
+            Err("day 04 is broken".to_string())
+        );
+    }
+}
@@ -0,0 +1,238 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use super::input::INPUT_DIR;
+
+pub const YEARS_DIR: &str = "src/years";
+
+const MAX_WIDTH: usize = 100;
+
+const DAY_TEMPLATE: &str = r#"use crate::{years::Day, Solution, SolutionPair, SolveError, Timer};
+
+pub const DAY: Day = Day {
+    number: {day},
+    title: "",
+    implemented: [false, false],
+    samples: [None, None],
+    solve,
+};
+
+pub fn solve(input: &str, timer: &mut Timer) -> Result<SolutionPair, SolveError> {
+    Ok((timer.part1(|| p1(input))?, timer.part2(|| p2(input))?))
+}
+
+fn p1(_input: &str) -> Solution {
+    Solution::None
+}
+
+fn p2(_input: &str) -> Solution {
+    Solution::None
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::etc::{Solution, Timer};
+
+    #[test]
+    fn test_sample_input() {
+        let input = include_str!("../../../input/{year}/day{day:02}/test.txt");
+        let (p1, p2) = super::solve(input, &mut Timer::default()).unwrap();
+        assert_eq!(p1, Solution::None);
+        assert_eq!(p2, Solution::None);
+    }
+}
+"#;
+
+const YEAR_TEMPLATE: &str = r#"use super::{Day, Year};
+
+pub const YEAR: Year = Year {
+    number: {year},
+    days: REGISTRY,
+};
+
+days![];
+"#;
+
+/// Creates day `day` of `year` and prints what was created.
+pub fn run(year: u16, day: u8) -> bool {
+    match create(Path::new(""), year, day) {
+        Ok(created) => {
+            for path in created {
+                println!("created {}", path.display());
+            }
+            println!("registered {} day {:02}, rebuild to run it", year, day);
+            true
+        }
+        Err(err) => {
+            eprintln!("error: could not create {} day {:02}: {}", year, day, err);
+            false
+        }
+    }
+}
+
+/// Generates the module and the empty sample input of a day under `root`, and registers the
+/// module in its year, creating and registering the year first if needed.
+/// Returns the files that were created.
+pub fn create(root: &Path, year: u16, day: u8) -> io::Result<Vec<PathBuf>> {
+    let years_dir = root.join(YEARS_DIR);
+    let year_dir = years_dir.join(format!("y{}", year));
+    let module = year_dir.join(format!("day{:02}.rs", day));
+    let input_dir = root
+        .join(INPUT_DIR)
+        .join(year.to_string())
+        .join(format!("day{:02}", day));
+
+    if module.exists() {
+        return Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            format!("{} already exists", module.display()),
+        ));
+    }
+
+    let mut created = Vec::new();
+
+    let year_module = year_dir.join("mod.rs");
+    if !year_module.exists() {
+        fs::create_dir_all(&year_dir)?;
+        fs::write(&year_module, fill(YEAR_TEMPLATE, year, day))?;
+        register(&years_dir.join("mod.rs"), "years", &format!("y{}", year))?;
+        created.push(year_module.clone());
+    }
+
+    fs::write(&module, fill(DAY_TEMPLATE, year, day))?;
+    created.push(module);
+    register(&year_module, "days", &format!("day{:02}", day))?;
+
+    fs::create_dir_all(&input_dir)?;
+    let sample = input_dir.join("test.txt");
+    if !sample.exists() {
+        fs::write(&sample, "")?;
+        created.push(sample);
+    }
+
+    Ok(created)
+}
+
+fn fill(template: &str, year: u16, day: u8) -> String {
+    template
+        .replace("{year}", &year.to_string())
+        .replace("{day:02}", &format!("{:02}", day))
+        .replace("{day}", &day.to_string())
+}
+
+/// Adds `module` to the `name![...]` invocation in the file at `path`.
+fn register(path: &Path, name: &str, module: &str) -> io::Result<()> {
+    let source = fs::read_to_string(path)?;
+    let updated = add_module(&source, name, module).ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("no {}![...] list in {}", name, path.display()),
+        )
+    })?;
+
+    fs::write(path, updated)
+}
+
+/// Inserts `module` into the `name![...];` list of `source`, keeping it sorted and laid out
+/// the way rustfmt would.
+fn add_module(source: &str, name: &str, module: &str) -> Option<String> {
+    let start = source
+        .match_indices(&format!("{}![", name))
+        .map(|(i, _)| i)
+        .find(|&i| i == 0 || source[..i].ends_with('\n'))?;
+    let end = start + source[start..].find("];")? + 2;
+
+    let mut modules = source[start + name.len() + 2..end - 2]
+        .split(',')
+        .map(str::trim)
+        .filter(|module| !module.is_empty())
+        .collect::<Vec<_>>();
+    modules.push(module);
+    modules.sort_unstable();
+    modules.dedup();
+
+    let single = format!("{}![{}];", name, modules.join(", "));
+    let list = if single.len() <= MAX_WIDTH {
+        single
+    } else {
+        let mut lines = vec![String::new()];
+        for module in modules {
+            let line = lines.last_mut().unwrap();
+            if !line.is_empty() && 4 + line.len() + 1 + module.len() + 1 > MAX_WIDTH {
+                lines.push(String::new());
+            }
+
+            let line = lines.last_mut().unwrap();
+            if !line.is_empty() {
+                line.push(' ');
+            }
+            line.push_str(module);
+            line.push(',');
+        }
+
+        let lines = lines
+            .iter()
+            .map(|line| format!("    {}\n", line))
+            .collect::<String>();
+        format!("{}![\n{}];", name, lines)
+    };
+
+    Some(format!("{}{}{}", &source[..start], list, &source[end..]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_add_module() {
+        let source = "use super::Day;\n\ndays![day01, day03];\n";
+        assert_eq!(
+            add_module(source, "days", "day02").unwrap(),
+            "use super::Day;\n\ndays![day01, day02, day03];\n"
+        );
+        assert!(add_module(source, "years", "y2024").is_none());
+
+        let registry = fs::read_to_string(Path::new(YEARS_DIR).join("y2023/mod.rs")).unwrap();
+        assert_eq!(add_module(&registry, "days", "day25").unwrap(), registry);
+
+        let empty = fill(YEAR_TEMPLATE, 2024, 1);
+        assert!(add_module(&empty, "days", "day01")
+            .unwrap()
+            .ends_with("\ndays![day01];\n"));
+    }
+
+    #[test]
+    fn test_create() {
+        let root = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        let years_dir = root.join(YEARS_DIR);
+        fs::create_dir_all(&years_dir).unwrap();
+        fs::write(years_dir.join("mod.rs"), "years![y2023];\n").unwrap();
+
+        let created = create(&root, 2024, 3).unwrap();
+        assert_eq!(
+            created,
+            vec![
+                years_dir.join("y2024/mod.rs"),
+                years_dir.join("y2024/day03.rs"),
+                root.join("input/2024/day03/test.txt"),
+            ]
+        );
+
+        let years = fs::read_to_string(years_dir.join("mod.rs")).unwrap();
+        assert_eq!(years, "years![y2023, y2024];\n");
+
+        let module = fs::read_to_string(years_dir.join("y2024/day03.rs")).unwrap();
+        assert!(module.contains("    number: 3,\n"));
+        assert!(module.contains("\"../../../input/2024/day03/test.txt\""));
+
+        create(&root, 2024, 1).unwrap();
+        let year = fs::read_to_string(years_dir.join("y2024/mod.rs")).unwrap();
+        assert!(year.ends_with("\ndays![day01, day03];\n"));
+        assert!(create(&root, 2024, 3).is_err());
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -1,7 +1,29 @@
-use std::fmt::{Display, Formatter, Result};
+use std::{
+    convert::Infallible,
+    fmt::{Display, Formatter, Result},
+    ops::{Add, Mul, Sub},
+    str::FromStr,
+};
 use Solution::*;
 
-#[derive(Debug, PartialEq, Clone)]
+use crate::utils::{bigint::BigInt, ocr};
+
+/// An answer. Integers compare by value whatever their variant, so `U32(142)` equals
+/// `Usize(142)`, and text compares by its letters, so a `Grid` equals the `Str` it spells.
+///
+/// Integers can also be added, subtracted and multiplied without overflowing: the result keeps
+/// a native variant whenever one holds it, preferring the operands' own, and only a result that
+/// outgrows them all becomes a `Big`. A `Big` that shrinks back becomes native again.
+///
+/// # Panics
+///
+/// `+`, `-` and `*` panic if either side is not an integer (`Str`, `Grid` or `None`).
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(tag = "type", content = "value", rename_all = "lowercase")
+)]
 pub enum Solution {
     None,
     I8(i8),
@@ -16,7 +38,12 @@ pub enum Solution {
     U64(u64),
     U128(u128),
     Usize(usize),
+    /// An integer too large for the other variants.
+    Big(BigInt),
     Str(String),
+    /// Letters drawn in pixels, one line per row, as read by `utils::ocr::decode`. Shown as the
+    /// letters it spells, or as is if they cannot be read.
+    Grid(String),
 }
 
 impl Display for Solution {
@@ -34,8 +61,163 @@ impl Display for Solution {
             U64(x) => x.fmt(f),
             U128(x) => x.fmt(f),
             Usize(x) => x.fmt(f),
+            Big(x) => x.fmt(f),
             Str(x) => x.fmt(f),
-            None => write!(f, "None")
+            Grid(x) => match ocr::decode(x) {
+                Some(text) => text.fmt(f),
+                Option::None => x.fmt(f),
+            },
+            None => write!(f, "None"),
+        }
+    }
+}
+
+impl PartialEq for Solution {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Str(a), Str(b)) | (Grid(a), Grid(b)) if a == b => true,
+            (Str(_) | Grid(_), Str(_) | Grid(_)) => {
+                matches!((self.text(), other.text()), (Some(a), Some(b)) if a == b)
+            }
+            (Big(a), Big(b)) => a == b,
+            (None, None) => true,
+            _ => matches!((self.integer(), other.integer()), (Some(a), Some(b)) if a == b),
+        }
+    }
+}
+
+impl Eq for Solution {}
+
+/// Parses a stored answer: `None`, an integer as the smallest of `I64`, `I128`, `U128` and
+/// `Big` that holds it, and anything else as a `Str`.
+impl FromStr for Solution {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        if s == "None" {
+            Ok(None)
+        } else if let Ok(x) = s.parse() {
+            Ok(I64(x))
+        } else if let Ok(x) = s.parse() {
+            Ok(I128(x))
+        } else if let Ok(x) = s.parse() {
+            Ok(U128(x))
+        } else if let Ok(x) = s.parse() {
+            Ok(Big(x))
+        } else {
+            Ok(Str(s.to_owned()))
+        }
+    }
+}
+
+impl Solution {
+    /// The sign and magnitude of an integer variant, which can hold the value of any of them
+    /// except a `Big` beyond `u128`.
+    fn integer(&self) -> Option<(bool, u128)> {
+        let signed = |x: i128| Some((x < 0, x.unsigned_abs()));
+        let unsigned = |x: u128| Some((false, x));
+
+        match *self {
+            I8(x) => signed(x.into()),
+            I16(x) => signed(x.into()),
+            I32(x) => signed(x.into()),
+            I64(x) => signed(x.into()),
+            I128(x) => signed(x),
+            Isize(x) => signed(x as i128),
+            U8(x) => unsigned(x.into()),
+            U16(x) => unsigned(x.into()),
+            U32(x) => unsigned(x.into()),
+            U64(x) => unsigned(x.into()),
+            U128(x) => unsigned(x),
+            Usize(x) => unsigned(x as u128),
+            Big(ref x) => x.sign_magnitude(),
+            Str(_) | Grid(_) | None => Option::None,
+        }
+    }
+
+    fn to_big(&self) -> Option<BigInt> {
+        match self {
+            Big(x) => Some(x.clone()),
+            Str(_) | Grid(_) | None => Option::None,
+            _ => self.integer().map(|(negative, magnitude)| {
+                let big = BigInt::from(magnitude);
+                if negative {
+                    -big
+                } else {
+                    big
+                }
+            }),
+        }
+    }
+
+    /// `value` in the same variant as `self`, if it is a native integer variant that holds it.
+    fn narrowed(&self, value: &BigInt) -> Option<Solution> {
+        match self {
+            I8(_) => i8::try_from(value).ok().map(I8),
+            I16(_) => i16::try_from(value).ok().map(I16),
+            I32(_) => i32::try_from(value).ok().map(I32),
+            I64(_) => i64::try_from(value).ok().map(I64),
+            I128(_) => i128::try_from(value).ok().map(I128),
+            Isize(_) => isize::try_from(value).ok().map(Isize),
+            U8(_) => u8::try_from(value).ok().map(U8),
+            U16(_) => u16::try_from(value).ok().map(U16),
+            U32(_) => u32::try_from(value).ok().map(U32),
+            U64(_) => u64::try_from(value).ok().map(U64),
+            U128(_) => u128::try_from(value).ok().map(U128),
+            Usize(_) => usize::try_from(value).ok().map(Usize),
+            Big(_) | Str(_) | Grid(_) | None => Option::None,
+        }
+    }
+
+    /// Width in bits of a native integer variant, and 0 for anything else.
+    fn bits(&self) -> u32 {
+        match self {
+            I8(_) | U8(_) => 8,
+            I16(_) | U16(_) => 16,
+            I32(_) | U32(_) => 32,
+            I64(_) | U64(_) => 64,
+            I128(_) | U128(_) => 128,
+            Isize(_) | Usize(_) => usize::BITS,
+            Big(_) | Str(_) | Grid(_) | None => 0,
+        }
+    }
+
+    /// The letters of a `Str`, or of a `Grid` that can be read.
+    fn text(&self) -> Option<String> {
+        match self {
+            Str(x) => Some(x.clone()),
+            Grid(x) => ocr::decode(x),
+            _ => Option::None,
+        }
+    }
+
+    /// The pixels of a `Grid`.
+    pub fn art(&self) -> Option<&str> {
+        match self {
+            Grid(x) => Some(x),
+            _ => Option::None,
+        }
+    }
+
+    /// Name of the type held by the variant, e.g. `usize` for `Solution::Usize`.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            I8(_) => "i8",
+            I16(_) => "i16",
+            I32(_) => "i32",
+            I64(_) => "i64",
+            I128(_) => "i128",
+            Isize(_) => "isize",
+            U8(_) => "u8",
+            U16(_) => "u16",
+            U32(_) => "u32",
+            U64(_) => "u64",
+            U128(_) => "u128",
+            Usize(_) => "usize",
+            Big(_) => "big",
+            Str(_) => "str",
+            Grid(_) => "grid",
+            None => "none",
         }
     }
 }
@@ -47,7 +229,7 @@ macro_rules! impl_from {
                 Self::$kind_(sol)
             }
         }
-    }
+    };
 }
 
 impl_from!(i8, I8);
@@ -62,10 +244,280 @@ impl_from!(u32, U32);
 impl_from!(u64, U64);
 impl_from!(u128, U128);
 impl_from!(usize, Usize);
+
+/// A `BigInt` becomes the first of `I64`, `I128` and `U128` that holds it, like a parsed answer,
+/// and a `Big` only beyond those.
+impl From<BigInt> for Solution {
+    fn from(x: BigInt) -> Self {
+        [I64(0), I128(0), U128(0)]
+            .iter()
+            .find_map(|like| like.narrowed(&x))
+            .unwrap_or(Big(x))
+    }
+}
+
 impl_from!(String, Str);
 
 impl From<&str> for Solution {
     fn from(sol: &str) -> Self {
         Self::Str(sol.to_owned())
     }
-}
\ No newline at end of file
+}
+
+/// Implements an operator on integer solutions that never overflows: if both sides have the
+/// same variant and the result fits, it keeps that variant. Otherwise the result takes the
+/// variant of the wider operand, then of the other one, then the first of `I64`, `I128` and
+/// `U128` that holds it, and only becomes a `Big` when none does. Panics if either side is not
+/// an integer.
+macro_rules! impl_checked_op {
+    ($trait_:ident, $method:ident, $checked:ident) => {
+        impl $trait_ for Solution {
+            type Output = Solution;
+
+            fn $method(self, rhs: Solution) -> Solution {
+                let native = match (&self, &rhs) {
+                    (I8(a), I8(b)) => a.$checked(*b).map(I8),
+                    (I16(a), I16(b)) => a.$checked(*b).map(I16),
+                    (I32(a), I32(b)) => a.$checked(*b).map(I32),
+                    (I64(a), I64(b)) => a.$checked(*b).map(I64),
+                    (I128(a), I128(b)) => a.$checked(*b).map(I128),
+                    (Isize(a), Isize(b)) => a.$checked(*b).map(Isize),
+                    (U8(a), U8(b)) => a.$checked(*b).map(U8),
+                    (U16(a), U16(b)) => a.$checked(*b).map(U16),
+                    (U32(a), U32(b)) => a.$checked(*b).map(U32),
+                    (U64(a), U64(b)) => a.$checked(*b).map(U64),
+                    (U128(a), U128(b)) => a.$checked(*b).map(U128),
+                    (Usize(a), Usize(b)) => a.$checked(*b).map(Usize),
+                    _ => Option::None,
+                };
+
+                if let Some(native) = native {
+                    return native;
+                }
+
+                let (Some(a), Some(b)) = (self.to_big(), rhs.to_big()) else {
+                    panic!(
+                        "cannot {} {} and {}",
+                        stringify!($method),
+                        self.kind(),
+                        rhs.kind()
+                    )
+                };
+                let result = a.$method(b);
+
+                let (wide, narrow) = if self.bits() >= rhs.bits() {
+                    (&self, &rhs)
+                } else {
+                    (&rhs, &self)
+                };
+                [wide, narrow]
+                    .into_iter()
+                    .find_map(|like| like.narrowed(&result))
+                    .unwrap_or_else(|| Solution::from(result))
+            }
+        }
+    };
+}
+
+impl_checked_op!(Add, add, checked_add);
+impl_checked_op!(Sub, sub, checked_sub);
+impl_checked_op!(Mul, mul, checked_mul);
+
+/// Why a `Solution` could not be converted to an integer type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TryFromSolutionError {
+    pub solution: Solution,
+    pub target: &'static str,
+}
+
+impl Display for TryFromSolutionError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self.solution {
+            Str(_) | Grid(_) | None => {
+                write!(f, "{} is not an integer", self.solution.kind())
+            }
+            _ => write!(f, "{} does not fit in {}", self.solution, self.target),
+        }
+    }
+}
+
+impl std::error::Error for TryFromSolutionError {}
+
+/// Converts any integer variant whose value fits in the target type.
+macro_rules! impl_try_from {
+    ($type_:ident) => {
+        impl TryFrom<Solution> for $type_ {
+            type Error = TryFromSolutionError;
+
+            fn try_from(sol: Solution) -> std::result::Result<Self, Self::Error> {
+                let value = match sol.integer() {
+                    Some((true, x)) => 0i128
+                        .checked_sub_unsigned(x)
+                        .and_then(|x| x.try_into().ok()),
+                    Some((false, x)) => x.try_into().ok(),
+                    Option::None => Option::None,
+                };
+
+                value.ok_or(TryFromSolutionError {
+                    solution: sol,
+                    target: stringify!($type_),
+                })
+            }
+        }
+    };
+}
+
+impl_try_from!(i8);
+impl_try_from!(i16);
+impl_try_from!(i32);
+impl_try_from!(i64);
+impl_try_from!(i128);
+impl_try_from!(isize);
+impl_try_from!(u8);
+impl_try_from!(u16);
+impl_try_from!(u32);
+impl_try_from!(u64);
+impl_try_from!(u128);
+impl_try_from!(usize);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_eq() {
+        assert_eq!(U32(142), Usize(142));
+        assert_eq!(I8(-3), I128(-3));
+        assert_eq!(U128(u128::MAX), U128(u128::MAX));
+        assert_ne!(U128(u128::MAX), I128(-1));
+        assert_ne!(I64(-1), U64(1));
+        assert_ne!(Str("142".to_string()), U32(142));
+        assert_ne!(None, Str("None".to_string()));
+        assert_eq!(None, None);
+
+        let big = Big(BigInt::from(u128::MAX) * BigInt::from(3));
+        assert_eq!(Big(BigInt::from(-5)), I8(-5));
+        assert_ne!(big, U128(u128::MAX));
+        assert_eq!(
+            big,
+            "1020847100762815390390123822295304634365".parse().unwrap()
+        );
+    }
+
+    #[test]
+    fn test_grid() {
+        let art = [
+            "#..#.####.####.",
+            "#..#.#.......#.",
+            "####.###....#..",
+            "#..#.#.....#...",
+            "#..#.#....#....",
+            "#..#.####.####.",
+        ]
+        .join("\n");
+        let grid = Grid(art.clone());
+
+        assert_eq!(grid.to_string(), "HEZ");
+        assert_eq!(grid, Solution::from("HEZ"));
+        assert_eq!(grid.art(), Some(art.as_str()));
+        assert_ne!(grid, Solution::from("HE"));
+
+        let unknown = Grid("#.#\n.#.".to_string());
+        assert_eq!(unknown.to_string(), "#.#\n.#.");
+        assert_eq!(unknown, unknown.clone());
+        assert_ne!(unknown, Solution::from("#.#\n.#."));
+    }
+
+    /// Serialized with the same `type` names as `kind`, e.g. `{"type":"u32","value":142}`.
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        let solutions = [
+            None,
+            I8(-8),
+            I16(16),
+            I32(-32),
+            I64(64),
+            I128(i128::MIN),
+            Isize(-1),
+            U8(8),
+            U16(16),
+            U32(142),
+            U64(u64::MAX),
+            U128(u128::MAX),
+            Usize(281),
+            Big(BigInt::from(u128::MAX) * BigInt::from(u128::MAX)),
+            Str("a \"b\"".to_string()),
+            Grid("#..#\n####".to_string()),
+        ];
+
+        for solution in solutions {
+            let json = serde_json::to_string(&solution).unwrap();
+            assert!(json.contains(&format!("\"type\":\"{}\"", solution.kind())));
+
+            let parsed = serde_json::from_str::<Solution>(&json).unwrap();
+            assert_eq!(parsed.kind(), solution.kind());
+            assert_eq!(parsed, solution);
+        }
+
+        assert_eq!(
+            serde_json::to_string(&U32(142)).unwrap(),
+            r#"{"type":"u32","value":142}"#
+        );
+        assert_eq!(serde_json::to_string(&None).unwrap(), r#"{"type":"none"}"#);
+        assert!(serde_json::from_str::<Solution>(r#"{"type":"u8","value":256}"#).is_err());
+    }
+
+    #[test]
+    fn test_checked_ops() {
+        assert!(matches!(U32(7) * U32(6), U32(42)));
+        assert!(matches!(Usize(7) + U8(1), Usize(8)));
+        assert!(matches!(U8(200) + U8(100), I64(300)));
+        assert!(matches!(Usize(2) - Usize(3), I64(-1)));
+        assert!(matches!(I8(-1) * U64(3), I8(-3)));
+
+        let product = U128(u128::MAX) * U8(2);
+        assert_eq!(product.kind(), "big");
+        assert_eq!(
+            product.to_string(),
+            "680564733841876926926749214863536422910"
+        );
+        assert!(matches!(product - U128(u128::MAX), U128(u128::MAX)));
+        assert!(matches!(Usize(2) - Usize(3) + Usize(5), I64(4)));
+    }
+
+    #[test]
+    fn test_from_str() {
+        let parse = |s: &str| s.parse::<Solution>().unwrap();
+        assert_eq!(parse("281"), U16(281));
+        assert!(matches!(parse("-2"), I64(-2)));
+        assert!(matches!(
+            parse("170141183460469231731687303715884105728"),
+            U128(_)
+        ));
+        assert!(matches!(
+            parse("-340282366920938463463374607431768211456"),
+            Big(_)
+        ));
+        assert_eq!(parse("ABC"), Solution::from("ABC"));
+        assert!(matches!(parse("None"), None));
+    }
+
+    #[test]
+    fn test_try_from() {
+        assert_eq!(u8::try_from(Usize(255)), Ok(255));
+        assert_eq!(i8::try_from(I64(-128)), Ok(-128));
+        assert_eq!(i128::try_from(I128(i128::MIN)), Ok(i128::MIN));
+        assert_eq!(usize::try_from(U128(7)), Ok(7));
+        assert_eq!(u64::try_from(Big(BigInt::from(9))), Ok(9));
+        assert!(u128::try_from(Big(BigInt::from(2).pow(128))).is_err());
+
+        let err = u8::try_from(U32(256)).unwrap_err();
+        assert_eq!(err.to_string(), "256 does not fit in u8");
+        assert!(u64::try_from(I32(-1)).is_err());
+        assert_eq!(
+            i32::try_from(Solution::from("x")).unwrap_err().to_string(),
+            "str is not an integer"
+        );
+    }
+}
@@ -0,0 +1,515 @@
+use std::{
+    fmt::{Display, Formatter},
+    fs, io,
+    path::Path,
+    str::FromStr,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use super::http::{Fetcher, Request};
+
+pub const HISTORY_FILE: &str = "submissions.txt";
+
+/// What the server said about a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    Correct,
+    Wrong,
+    TooHigh,
+    TooLow,
+    /// The answer was not checked because the last one was submitted too recently.
+    RateLimited,
+    /// The part was already solved, or is not unlocked yet.
+    WrongLevel,
+}
+
+impl Outcome {
+    pub fn is_wrong(self) -> bool {
+        matches!(self, Outcome::Wrong | Outcome::TooHigh | Outcome::TooLow)
+    }
+
+    fn key(self) -> &'static str {
+        match self {
+            Outcome::Correct => "correct",
+            Outcome::Wrong => "wrong",
+            Outcome::TooHigh => "too_high",
+            Outcome::TooLow => "too_low",
+            Outcome::RateLimited => "rate_limited",
+            Outcome::WrongLevel => "wrong_level",
+        }
+    }
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Outcome::Correct => write!(f, "correct"),
+            Outcome::Wrong => write!(f, "wrong"),
+            Outcome::TooHigh => write!(f, "wrong, too high"),
+            Outcome::TooLow => write!(f, "wrong, too low"),
+            Outcome::RateLimited => write!(f, "not checked, submitted too recently"),
+            Outcome::WrongLevel => write!(f, "not checked, already solved or not unlocked"),
+        }
+    }
+}
+
+impl FromStr for Outcome {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        [
+            Outcome::Correct,
+            Outcome::Wrong,
+            Outcome::TooHigh,
+            Outcome::TooLow,
+            Outcome::RateLimited,
+            Outcome::WrongLevel,
+        ]
+        .into_iter()
+        .find(|outcome| outcome.key() == s)
+        .ok_or_else(|| format!("unknown outcome '{}'", s))
+    }
+}
+
+/// The server's verdict on a submission, and how long to wait before the next one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Reply {
+    pub outcome: Outcome,
+    pub wait: Duration,
+}
+
+impl Reply {
+    /// Reads the verdict out of the HTML page returned for a submission.
+    pub fn parse(page: &str) -> Option<Self> {
+        let text = page.to_lowercase();
+
+        let outcome = if text.contains("that's the right answer") {
+            Outcome::Correct
+        } else if text.contains("that's not the right answer") {
+            if text.contains("your answer is too high") {
+                Outcome::TooHigh
+            } else if text.contains("your answer is too low") {
+                Outcome::TooLow
+            } else {
+                Outcome::Wrong
+            }
+        } else if text.contains("you gave an answer too recently") {
+            Outcome::RateLimited
+        } else if text.contains("you don't seem to be solving the right level") {
+            Outcome::WrongLevel
+        } else {
+            return None;
+        };
+
+        Some(Reply {
+            outcome,
+            wait: parse_wait(&text).unwrap_or_default(),
+        })
+    }
+}
+
+/// Finds the cooldown in texts like "please wait one minute before trying again" or
+/// "you have 1m 5s left to wait".
+fn parse_wait(text: &str) -> Option<Duration> {
+    if let Some(end) = text.find(" left to wait") {
+        let start = text[..end].rfind("you have ")? + "you have ".len();
+
+        let mut seconds = 0;
+        for amount in text[start..end].split_whitespace() {
+            let (number, unit) = amount.split_at(amount.find(|c: char| !c.is_ascii_digit())?);
+            let number = number.parse::<u64>().ok()?;
+            seconds += match unit {
+                "h" => number * 3600,
+                "m" => number * 60,
+                "s" => number,
+                _ => return None,
+            };
+        }
+        return Some(Duration::from_secs(seconds));
+    }
+
+    let start = text.find("please wait ")?;
+    let mut words = text[start + "please wait ".len()..].split_whitespace();
+    let number = match words.next()? {
+        "one" => 1,
+        "two" => 2,
+        "five" => 5,
+        "ten" => 10,
+        number => number.parse().ok()?,
+    };
+
+    match words.next()? {
+        "minute" | "minutes" => Some(Duration::from_secs(number * 60)),
+        "second" | "seconds" => Some(Duration::from_secs(number)),
+        _ => None,
+    }
+}
+
+/// Why an answer is not worth submitting.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Refusal {
+    /// The part was already solved with this answer.
+    AlreadySolved(String),
+    /// This exact answer was already submitted and is wrong.
+    KnownWrong(String),
+    /// `answer` is at least `bound`, which was already too high.
+    TooHigh { answer: String, bound: String },
+    /// `answer` is at most `bound`, which was already too low.
+    TooLow { answer: String, bound: String },
+    /// The cooldown of the last submission of the day is not over yet.
+    Wait(Duration),
+}
+
+impl Display for Refusal {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Refusal::AlreadySolved(answer) => {
+                write!(f, "this part is already solved, its answer is {}", answer)
+            }
+            Refusal::KnownWrong(answer) => {
+                write!(f, "{} was already submitted and is wrong", answer)
+            }
+            Refusal::TooHigh { answer, bound } => write!(
+                f,
+                "{} is not below {}, which was already too high",
+                answer, bound
+            ),
+            Refusal::TooLow { answer, bound } => write!(
+                f,
+                "{} is not above {}, which was already too low",
+                answer, bound
+            ),
+            Refusal::Wait(wait) => write!(
+                f,
+                "the last answer for this day was sent too recently, try again in {}s",
+                wait.as_secs()
+            ),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Submission {
+    /// Seconds since the Unix epoch.
+    pub time: u64,
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub reply: Reply,
+}
+
+/// Every answer submitted so far, stored as one whitespace separated line per submission:
+/// `time year day part outcome wait answer`, with `time` and `wait` in seconds.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct History {
+    submissions: Vec<Submission>,
+}
+
+impl History {
+    pub fn load(path: &Path) -> io::Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(contents) => contents.parse(),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(History::default()),
+            Err(err) => Err(err),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        fs::write(path, self.to_string())
+    }
+
+    pub fn push(&mut self, submission: Submission) {
+        self.submissions.push(submission);
+    }
+
+    /// Checks `answer` against what earlier submissions of the same part revealed, and
+    /// against the cooldown of the last submission of the day.
+    pub fn check(
+        &self,
+        year: u16,
+        day: u8,
+        part: u8,
+        answer: &str,
+        now: u64,
+    ) -> Result<(), Refusal> {
+        let same_day = self
+            .submissions
+            .iter()
+            .filter(|s| s.year == year && s.day == day)
+            .collect::<Vec<_>>();
+
+        if let Some(last) = same_day.last() {
+            let ready = last.time + last.reply.wait.as_secs();
+            if now < ready {
+                return Err(Refusal::Wait(Duration::from_secs(ready - now)));
+            }
+        }
+
+        let value = answer.parse::<i128>().ok();
+        for submission in same_day.iter().filter(|s| s.part == part) {
+            let previous = submission.answer.parse::<i128>().ok();
+
+            match submission.reply.outcome {
+                Outcome::Correct => return Err(Refusal::AlreadySolved(submission.answer.clone())),
+                outcome if outcome.is_wrong() && submission.answer == answer => {
+                    return Err(Refusal::KnownWrong(answer.to_string()))
+                }
+                Outcome::TooHigh if matches!((value, previous), (Some(v), Some(p)) if v >= p) => {
+                    return Err(Refusal::TooHigh {
+                        answer: answer.to_string(),
+                        bound: submission.answer.clone(),
+                    })
+                }
+                Outcome::TooLow if matches!((value, previous), (Some(v), Some(p)) if v <= p) => {
+                    return Err(Refusal::TooLow {
+                        answer: answer.to_string(),
+                        bound: submission.answer.clone(),
+                    })
+                }
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Display for History {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "# time year day part outcome wait answer")?;
+        for s in &self.submissions {
+            writeln!(
+                f,
+                "{} {} {} {} {} {} {}",
+                s.time,
+                s.year,
+                s.day,
+                s.part,
+                s.reply.outcome.key(),
+                s.reply.wait.as_secs(),
+                s.answer
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for History {
+    type Err = io::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = |line: usize, reason: &str| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("line {}: {}", line, reason),
+            )
+        };
+
+        let mut history = History::default();
+
+        for (i, line) in s.lines().enumerate() {
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let fields = line.splitn(7, ' ').collect::<Vec<_>>();
+            let [time, year, day, part, outcome, wait, answer] = fields.as_slice() else {
+                return Err(invalid(i + 1, "expected 7 fields"));
+            };
+            let number = |field: &str| {
+                field
+                    .parse::<u64>()
+                    .map_err(|_| invalid(i + 1, "invalid number"))
+            };
+
+            history.push(Submission {
+                time: number(time)?,
+                year: number(year)? as u16,
+                day: number(day)? as u8,
+                part: number(part)? as u8,
+                answer: answer.to_string(),
+                reply: Reply {
+                    outcome: outcome
+                        .parse()
+                        .map_err(|err: String| invalid(i + 1, &err))?,
+                    wait: Duration::from_secs(number(wait)?),
+                },
+            });
+        }
+
+        Ok(history)
+    }
+}
+
+/// Posts answers to the Advent of Code server, or to whatever stands in for it.
+pub struct Client {
+    url: String,
+    session: String,
+    fetcher: Box<dyn Fetcher>,
+}
+
+impl Client {
+    pub fn new(
+        url: impl Into<String>,
+        session: impl Into<String>,
+        fetcher: Box<dyn Fetcher>,
+    ) -> Self {
+        Self {
+            url: url.into(),
+            session: session.into(),
+            fetcher,
+        }
+    }
+
+    pub fn submit(&self, year: u16, day: u8, part: u8, answer: &str) -> io::Result<Reply> {
+        let url = format!(
+            "{}/{}/day/{}/answer",
+            self.url.trim_end_matches('/'),
+            year,
+            day
+        );
+        let request = Request::get(url)
+            .with_session(&self.session)
+            .with_form(&[("level", &part.to_string()), ("answer", answer)]);
+
+        let response = self.fetcher.send(&request)?;
+        if !response.is_success() {
+            return Err(io::Error::other(format!(
+                "server replied with status {}",
+                response.status
+            )));
+        }
+
+        Reply::parse(&response.body)
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "unrecognized reply"))
+    }
+}
+
+pub fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::etc::http::{stub::StubServer, HttpFetcher};
+
+    const TOO_HIGH: &str = "<article><p>That's not the right answer; your answer is too high.  \
+        If you're stuck, make sure you're using the full input data.  Please wait one minute \
+        before trying again. [<a href=\"/2023/day/1\">Return to Day 1</a>]</p></article>";
+    const RATE_LIMITED: &str = "<article><p>You gave an answer too recently; you have to wait \
+        after submitting an answer before trying again.  You have 1m 5s left to wait.</p></article>";
+
+    #[test]
+    fn test_parse_reply() {
+        let reply = |page| Reply::parse(page).unwrap();
+
+        assert_eq!(
+            reply(TOO_HIGH),
+            Reply {
+                outcome: Outcome::TooHigh,
+                wait: Duration::from_secs(60)
+            }
+        );
+        assert_eq!(reply(RATE_LIMITED).wait, Duration::from_secs(65));
+        assert_eq!(
+            reply("<p>That's the right answer!  You are one gold star closer.</p>").outcome,
+            Outcome::Correct
+        );
+        assert_eq!(
+            reply(
+                "<p>That's not the right answer.  Please wait 5 minutes before trying again.</p>"
+            ),
+            Reply {
+                outcome: Outcome::Wrong,
+                wait: Duration::from_secs(300)
+            }
+        );
+        assert_eq!(
+            reply("<p>You don't seem to be solving the right level.  Did you already complete it?</p>")
+                .outcome,
+            Outcome::WrongLevel
+        );
+        assert!(Reply::parse("<html>Something else</html>").is_none());
+    }
+
+    #[test]
+    fn test_history() {
+        let submission = |time, part, answer: &str, outcome, wait| Submission {
+            time,
+            year: 2023,
+            day: 1,
+            part,
+            answer: answer.to_string(),
+            reply: Reply {
+                outcome,
+                wait: Duration::from_secs(wait),
+            },
+        };
+
+        let mut history = History::default();
+        history.push(submission(100, 1, "500", Outcome::TooHigh, 60));
+        history.push(submission(200, 1, "100", Outcome::TooLow, 60));
+        history.push(submission(300, 2, "a b", Outcome::Wrong, 0));
+
+        let parsed = history.to_string().parse::<History>().unwrap();
+        assert_eq!(parsed, history);
+
+        assert_eq!(history.check(2023, 1, 1, "300", 400), Ok(()));
+        assert_eq!(history.check(2023, 2, 1, "500", 400), Ok(()));
+        let too_high = history.check(2023, 1, 1, "600", 400).unwrap_err();
+        assert_eq!(
+            too_high.to_string(),
+            "600 is not below 500, which was already too high"
+        );
+        let too_low = history.check(2023, 1, 1, "50", 400).unwrap_err();
+        assert_eq!(
+            too_low.to_string(),
+            "50 is not above 100, which was already too low"
+        );
+        assert_eq!(
+            history.check(2023, 1, 2, "a b", 400),
+            Err(Refusal::KnownWrong("a b".to_string()))
+        );
+
+        history.push(submission(400, 1, "300", Outcome::Correct, 0));
+        assert_eq!(
+            history.check(2023, 1, 1, "301", 500),
+            Err(Refusal::AlreadySolved("300".to_string()))
+        );
+
+        history.push(submission(500, 2, "7", Outcome::RateLimited, 65));
+        assert_eq!(
+            history.check(2023, 1, 2, "8", 540),
+            Err(Refusal::Wait(Duration::from_secs(25)))
+        );
+        assert_eq!(history.check(2023, 1, 2, "7", 600), Ok(()));
+    }
+
+    #[test]
+    fn test_client() {
+        let server = StubServer::start(|request| match request.body.as_str() {
+            "level=1&answer=42" => (200, "<p>That's the right answer!</p>".to_string()),
+            _ => (200, TOO_HIGH.to_string()),
+        });
+        let client = Client::new(&server.url, "secret", Box::new(HttpFetcher));
+
+        assert_eq!(
+            client.submit(2023, 1, 1, "42").unwrap().outcome,
+            Outcome::Correct
+        );
+        assert_eq!(
+            client.submit(2023, 1, 2, "99").unwrap().outcome,
+            Outcome::TooHigh
+        );
+
+        let received = server.received();
+        assert_eq!(received[0].method, "POST");
+        assert_eq!(received[0].path, "/2023/day/1/answer");
+        assert_eq!(received[0].cookie.as_deref(), Some("session=secret"));
+        assert_eq!(received[1].body, "level=2&answer=99");
+    }
+}
@@ -0,0 +1,143 @@
+use std::{
+    ops::{AddAssign, Div},
+    time::{Duration, Instant},
+};
+
+use super::{
+    alloc::{self, Allocations},
+    Solution, SolveError,
+};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Timings {
+    pub parse: Duration,
+    pub part1: Duration,
+    pub part2: Duration,
+    /// Only counted with the `alloc-stats` feature.
+    pub allocs: Allocations,
+}
+
+impl Timings {
+    pub fn total(&self) -> Duration {
+        self.parse + self.part1 + self.part2
+    }
+}
+
+impl AddAssign for Timings {
+    fn add_assign(&mut self, rhs: Self) {
+        self.parse += rhs.parse;
+        self.part1 += rhs.part1;
+        self.part2 += rhs.part2;
+        self.allocs += rhs.allocs;
+    }
+}
+
+impl Div<u32> for Timings {
+    type Output = Timings;
+
+    fn div(self, rhs: u32) -> Self::Output {
+        Timings {
+            parse: self.parse / rhs,
+            part1: self.part1 / rhs,
+            part2: self.part2 / rhs,
+            allocs: self.allocs / rhs,
+        }
+    }
+}
+
+/// What a part can return: either a `Solution`, or a `Result` for parts that can fail.
+pub trait IntoAnswer {
+    fn into_answer(self) -> Result<Solution, SolveError>;
+}
+
+impl IntoAnswer for Solution {
+    fn into_answer(self) -> Result<Solution, SolveError> {
+        Ok(self)
+    }
+}
+
+impl IntoAnswer for Result<Solution, SolveError> {
+    fn into_answer(self) -> Result<Solution, SolveError> {
+        self
+    }
+}
+
+/// Times the phases of a solver. Parts that were not selected are skipped and
+/// answered with `Solution::None`.
+#[derive(Debug, Default)]
+pub struct Timer {
+    part: Option<u8>,
+    timings: Timings,
+}
+
+impl Timer {
+    pub fn new(part: Option<u8>) -> Self {
+        Self {
+            part,
+            timings: Timings::default(),
+        }
+    }
+
+    pub fn timings(&self) -> Timings {
+        self.timings
+    }
+
+    pub fn parse<T>(&mut self, f: impl FnOnce() -> T) -> T {
+        let ((value, elapsed), allocs) = alloc::measure(|| time(f));
+        self.timings.parse += elapsed;
+        self.timings.allocs.parse += allocs;
+        value
+    }
+
+    pub fn part1<A: IntoAnswer>(&mut self, f: impl FnOnce() -> A) -> Result<Solution, SolveError> {
+        if !self.runs_part(1) {
+            return Ok(Solution::None);
+        }
+
+        let ((answer, elapsed), allocs) = alloc::measure(|| time(f));
+        self.timings.part1 += elapsed;
+        self.timings.allocs.part1 += allocs;
+        answer.into_answer()
+    }
+
+    pub fn part2<A: IntoAnswer>(&mut self, f: impl FnOnce() -> A) -> Result<Solution, SolveError> {
+        if !self.runs_part(2) {
+            return Ok(Solution::None);
+        }
+
+        let ((answer, elapsed), allocs) = alloc::measure(|| time(f));
+        self.timings.part2 += elapsed;
+        self.timings.allocs.part2 += allocs;
+        answer.into_answer()
+    }
+
+    fn runs_part(&self, part: u8) -> bool {
+        self.part.is_none_or(|p| p == part)
+    }
+}
+
+pub fn as_ms(duration: Duration) -> f64 {
+    duration.as_nanos() as f64 / 1_000_000.0
+}
+
+fn time<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let value = f();
+    (value, start.elapsed())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_skips_unselected_part() {
+        let mut timer = Timer::new(Some(2));
+        let p1 = timer.part1(|| -> Solution { unreachable!("part 1 should be skipped") });
+        let p2 = timer.part2(|| Solution::U8(2));
+
+        assert_eq!(p1, Ok(Solution::None));
+        assert_eq!(p2, Ok(Solution::U8(2)));
+        assert_eq!(timer.timings().part1, Duration::ZERO);
+    }
+}
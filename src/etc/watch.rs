@@ -0,0 +1,284 @@
+use std::{
+    collections::BTreeMap,
+    env,
+    ffi::OsString,
+    fs,
+    path::{Path, PathBuf},
+    process::{Command, Output},
+    thread,
+    time::{Duration, SystemTime},
+};
+
+use super::{
+    cli::Options,
+    input::{self, Source},
+    scaffold::YEARS_DIR,
+};
+
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// The answers of a run of the real input, by part.
+type Answers = BTreeMap<u8, String>;
+
+/// Re-runs the sample tests and the real input of a day every time its module or one of its
+/// input files changes. Only returns if the day cannot be watched.
+pub fn run(options: &Options) -> bool {
+    let (year, day) = (options.year, options.days[0]);
+    let module = Path::new(YEARS_DIR)
+        .join(format!("y{}", year))
+        .join(format!("day{:02}.rs", day));
+    if !module.exists() {
+        eprintln!("error: {} does not exist", module.display());
+        return false;
+    }
+
+    println!(
+        "Watching {} and {}, press Ctrl-C to stop",
+        module.display(),
+        input::day_dir(year, day).display()
+    );
+
+    let mut previous = None;
+    let mut seen = Vec::new();
+    let mut runs = 0;
+
+    loop {
+        let current = snapshot(&module, &input::day_dir(year, day));
+        if current != seen {
+            seen = current;
+            runs += 1;
+
+            println!("\n=== Day {:02}, run {} ===", day, runs);
+            if let Some(answers) = check(options, previous.as_ref()) {
+                previous = Some(answers);
+            }
+        }
+
+        thread::sleep(POLL_INTERVAL);
+    }
+}
+
+/// The modification times of the module and of every file in the input directory.
+fn snapshot(module: &Path, input_dir: &Path) -> Vec<(PathBuf, Option<SystemTime>)> {
+    let mut files = vec![module.to_path_buf()];
+    if let Ok(entries) = fs::read_dir(input_dir) {
+        files.extend(entries.filter_map(Result::ok).map(|entry| entry.path()));
+    }
+    files.sort();
+
+    files
+        .into_iter()
+        .map(|path| {
+            let modified = fs::metadata(&path).and_then(|m| m.modified()).ok();
+            (path, modified)
+        })
+        .collect()
+}
+
+/// Rebuilds, runs the sample tests and then the real input, and prints how the answers
+/// changed since `previous`. Returns the new answers, if the day could be run.
+fn check(options: &Options, previous: Option<&Answers>) -> Option<Answers> {
+    let filter = format!("years::y{}::day{:02}::", options.year, options.days[0]);
+    let tests = cargo(&["test", "--release", "--lib", "--", &filter])?;
+
+    let results = parse_test_results(&String::from_utf8_lossy(&tests.stdout));
+    if results.is_empty() && !tests.status.success() {
+        println!(
+            "  · Build failed:\n{}",
+            String::from_utf8_lossy(&tests.stderr)
+        );
+        return None;
+    }
+    for (test, passed) in &results {
+        println!("  · {}: {}", test, if *passed { "ok" } else { "FAILED" });
+    }
+
+    if options.source == Source::Example {
+        return None;
+    }
+
+    let mut args = vec![
+        "run".to_string(),
+        "--release".to_string(),
+        "--quiet".to_string(),
+        "--".to_string(),
+        "--quiet".to_string(),
+        "--no-history".to_string(),
+        "--format".to_string(),
+        "csv".to_string(),
+        "--year".to_string(),
+        options.year.to_string(),
+    ];
+    if let Some(path) = &options.input {
+        args.extend(["--input".to_string(), path.display().to_string()]);
+    }
+    if options.offline {
+        args.push("--offline".to_string());
+    }
+    if let Some(part) = options.part {
+        args.extend(["--part".to_string(), part.to_string()]);
+    }
+    args.push(options.days[0].to_string());
+
+    let run = cargo(&args.iter().map(String::as_str).collect::<Vec<_>>())?;
+    let stderr = String::from_utf8_lossy(&run.stderr);
+    for error in stderr.lines().filter(|line| line.starts_with("error")) {
+        println!("  · {}", error);
+    }
+    if !run.status.success() {
+        return None;
+    }
+
+    let answers = parse_answers(&String::from_utf8_lossy(&run.stdout));
+    for line in diff_answers(previous, &answers) {
+        println!("  · {}", line);
+    }
+
+    Some(answers)
+}
+
+fn cargo(args: &[&str]) -> Option<Output> {
+    let cargo = env::var_os("CARGO").unwrap_or_else(|| OsString::from("cargo"));
+
+    match Command::new(cargo).args(args).output() {
+        Ok(output) => Some(output),
+        Err(err) => {
+            println!("  · Could not run cargo: {}", err);
+            None
+        }
+    }
+}
+
+/// The name and outcome of every test in the output of `cargo test`.
+fn parse_test_results(output: &str) -> Vec<(String, bool)> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let (name, result) = line.strip_prefix("test ")?.split_once(" ... ")?;
+            let name = name.rsplit("::").next()?;
+
+            match result.trim() {
+                "ok" => Some((name.to_string(), true)),
+                "FAILED" => Some((name.to_string(), false)),
+                _ => None,
+            }
+        })
+        .collect()
+}
+
+/// Describes each answer of the real input, compared to the same part in the previous run.
+fn diff_answers(previous: Option<&Answers>, answers: &Answers) -> Vec<String> {
+    answers
+        .iter()
+        .map(|(part, answer)| {
+            let before = previous.and_then(|previous| previous.get(part));
+            match before {
+                Some(before) if before == answer => {
+                    format!("Part {}: {} (unchanged)", part, answer)
+                }
+                Some(before) => format!("Part {}: {} (was {})", part, answer, before),
+                None => format!("Part {}: {}", part, answer),
+            }
+        })
+        .collect()
+}
+
+/// Reads the answers back from the CSV records of a run, whose values can span several lines.
+fn parse_answers(csv: &str) -> Answers {
+    let mut records = vec![vec![String::new()]];
+    let mut quoted = false;
+    let mut chars = csv.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        let record = records.last_mut().unwrap();
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                chars.next();
+                record.last_mut().unwrap().push('"');
+            }
+            '"' => quoted = !quoted,
+            ',' if !quoted => record.push(String::new()),
+            '\n' if !quoted => records.push(vec![String::new()]),
+            c => record.last_mut().unwrap().push(c),
+        }
+    }
+
+    // The columns are day, part, type and value
+    records
+        .iter()
+        .skip(1)
+        .filter_map(|record| {
+            let part = record.get(1)?.parse().ok()?;
+            let answer = match record.get(2)?.as_str() {
+                "none" => "None".to_string(),
+                _ => record.get(3)?.clone(),
+            };
+            Some((part, answer))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_test_results() {
+        let output = "
+running 2 tests
+test years::y2023::day18::tests::test_invalid_input ... ok
+test years::y2023::day18::tests::test_sample_input ... FAILED
+
+test result: FAILED. 1 passed; 1 failed; 0 ignored; 0 measured; 59 filtered out";
+
+        assert_eq!(
+            parse_test_results(output),
+            vec![
+                ("test_invalid_input".to_string(), true),
+                ("test_sample_input".to_string(), false)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_diff_answers() {
+        let before = Answers::from([(1, "62".to_string()), (2, "100".to_string())]);
+        let after = Answers::from([(1, "62".to_string()), (2, "952408144115".to_string())]);
+
+        assert_eq!(
+            diff_answers(Some(&before), &after),
+            vec!["Part 1: 62 (unchanged)", "Part 2: 952408144115 (was 100)"]
+        );
+        assert_eq!(
+            diff_answers(None, &after),
+            vec!["Part 1: 62", "Part 2: 952408144115"]
+        );
+
+        // With --part 2 only the second answer is compared, against the same part
+        let only_part_2 = Answers::from([(2, "100".to_string())]);
+        assert_eq!(
+            diff_answers(Some(&before), &only_part_2),
+            vec!["Part 2: 100 (unchanged)"]
+        );
+    }
+
+    #[test]
+    fn test_parse_answers() {
+        let csv = "day,part,type,value,parse_ns,elapsed_ns
+10,1,grid,\"#..#
+####\",5,7
+10,2,str,\"say \"\"hi\"\", twice\",5,7
+";
+        assert_eq!(
+            parse_answers(csv),
+            Answers::from([
+                (1, "#..#\n####".to_string()),
+                (2, "say \"hi\", twice".to_string())
+            ])
+        );
+        assert_eq!(
+            parse_answers("day,part,type,value,parse_ns,elapsed_ns\n25,2,none,,1,0\n"),
+            Answers::from([(2, "None".to_string())])
+        );
+    }
+}
@@ -0,0 +1,7 @@
+pub mod etc;
+pub mod utils;
+pub mod years;
+
+pub use etc::{Solution, SolveError, Timer};
+
+pub type SolutionPair = (Solution, Solution);
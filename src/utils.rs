@@ -1,2 +1,6 @@
+pub mod bigint;
+pub mod grid;
+pub mod ocr;
+pub mod parse;
 pub mod vector_2d;
 pub mod vector_3d;
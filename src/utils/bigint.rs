@@ -0,0 +1,498 @@
+use std::{
+    cmp::Ordering,
+    fmt::{Display, Formatter},
+    ops::{Add, Div, Mul, Neg, Rem, Sub},
+    str::FromStr,
+};
+
+/// An arbitrary-precision signed integer, for answers that do not fit in `i128` or `u128`.
+#[derive(Clone, Debug, Default, Hash, PartialEq, Eq)]
+pub struct BigInt {
+    negative: bool,
+    /// The magnitude in base 2^32, least significant digit first and without leading zeros,
+    /// so zero has no digits and is never negative.
+    digits: Vec<u32>,
+}
+
+/// Serialized as a string of decimal digits, since most formats have no integers this large.
+#[cfg(feature = "serde")]
+impl serde::Serialize for BigInt {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for BigInt {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let decimal = <std::borrow::Cow<str>>::deserialize(deserializer)?;
+        decimal.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseBigIntError;
+
+impl Display for ParseBigIntError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid integer")
+    }
+}
+
+impl std::error::Error for ParseBigIntError {}
+
+/// The value of a `BigInt` does not fit in the requested integer type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TryFromBigIntError;
+
+impl Display for TryFromBigIntError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "out of range integral type conversion attempted")
+    }
+}
+
+impl std::error::Error for TryFromBigIntError {}
+
+impl BigInt {
+    fn new(negative: bool, mut digits: Vec<u32>) -> Self {
+        while digits.last() == Some(&0) {
+            digits.pop();
+        }
+        Self {
+            negative: negative && !digits.is_empty(),
+            digits,
+        }
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.digits.is_empty()
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    pub fn abs(&self) -> BigInt {
+        BigInt::new(false, self.digits.clone())
+    }
+
+    /// The sign and the magnitude, if the magnitude fits in a `u128`.
+    pub fn sign_magnitude(&self) -> Option<(bool, u128)> {
+        if self.digits.len() > 4 {
+            return None;
+        }
+        let magnitude = self
+            .digits
+            .iter()
+            .rev()
+            .fold(0, |acc, &digit| (acc << 32) | digit as u128);
+        Some((self.negative, magnitude))
+    }
+
+    /// The greatest common divisor of the absolute values, which is zero only if both are.
+    pub fn gcd(&self, other: &BigInt) -> BigInt {
+        let (mut a, mut b) = (self.abs(), other.abs());
+        while !b.is_zero() {
+            let r = &a % &b;
+            a = b;
+            b = r;
+        }
+        a
+    }
+
+    pub fn pow(&self, mut exp: u32) -> BigInt {
+        let mut base = self.clone();
+        let mut result = BigInt::from(1);
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = &result * &base;
+            }
+            base = &base * &base;
+            exp >>= 1;
+        }
+        result
+    }
+}
+
+fn cmp_digits(a: &[u32], b: &[u32]) -> Ordering {
+    a.len()
+        .cmp(&b.len())
+        .then_with(|| a.iter().rev().cmp(b.iter().rev()))
+}
+
+fn add_digits(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let (long, short) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+    let mut sum = Vec::with_capacity(long.len() + 1);
+    let mut carry = 0;
+
+    for (i, &digit) in long.iter().enumerate() {
+        let total = digit as u64 + *short.get(i).unwrap_or(&0) as u64 + carry;
+        sum.push(total as u32);
+        carry = total >> 32;
+    }
+    sum.push(carry as u32);
+    sum
+}
+
+/// `a - b`, where `a` is at least `b`.
+fn sub_digits(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let mut difference = Vec::with_capacity(a.len());
+    let mut borrow = 0;
+
+    for (i, &digit) in a.iter().enumerate() {
+        let subtrahend = *b.get(i).unwrap_or(&0) as i64 + borrow;
+        let mut value = digit as i64 - subtrahend;
+        borrow = 0;
+        if value < 0 {
+            value += 1 << 32;
+            borrow = 1;
+        }
+        difference.push(value as u32);
+    }
+    difference
+}
+
+fn mul_digits(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let mut product = vec![0u32; a.len() + b.len()];
+
+    for (i, &x) in a.iter().enumerate() {
+        let mut carry = 0u64;
+        for (j, &y) in b.iter().enumerate() {
+            let total = x as u64 * y as u64 + product[i + j] as u64 + carry;
+            product[i + j] = total as u32;
+            carry = total >> 32;
+        }
+        product[i + b.len()] = carry as u32;
+    }
+    product
+}
+
+/// Quotient and remainder of `a / b`, by binary long division. `b` must not be zero.
+fn div_rem_digits(a: &[u32], b: &[u32]) -> (Vec<u32>, Vec<u32>) {
+    if let [divisor] = *b {
+        let (quotient, remainder) = div_rem_small(a, divisor);
+        return (quotient, vec![remainder]);
+    }
+
+    let mut quotient = vec![0u32; a.len()];
+    let mut remainder = Vec::<u32>::new();
+
+    for bit in (0..a.len() * 32).rev() {
+        // remainder = remainder * 2 + the next bit of `a`
+        let mut carry = (a[bit / 32] >> (bit % 32)) & 1;
+        for digit in remainder.iter_mut() {
+            let next = *digit >> 31;
+            *digit = (*digit << 1) | carry;
+            carry = next;
+        }
+        if carry != 0 {
+            remainder.push(carry);
+        }
+
+        if cmp_digits(&remainder, b) != Ordering::Less {
+            remainder = sub_digits(&remainder, b);
+            while remainder.last() == Some(&0) {
+                remainder.pop();
+            }
+            quotient[bit / 32] |= 1 << (bit % 32);
+        }
+    }
+    (quotient, remainder)
+}
+
+fn div_rem_small(a: &[u32], divisor: u32) -> (Vec<u32>, u32) {
+    let mut quotient = vec![0u32; a.len()];
+    let mut remainder = 0u64;
+
+    for (i, &digit) in a.iter().enumerate().rev() {
+        let current = (remainder << 32) | digit as u64;
+        quotient[i] = (current / divisor as u64) as u32;
+        remainder = current % divisor as u64;
+    }
+    (quotient, remainder as u32)
+}
+
+impl From<u128> for BigInt {
+    fn from(value: u128) -> Self {
+        let digits = (0..4).map(|i| (value >> (32 * i)) as u32).collect();
+        BigInt::new(false, digits)
+    }
+}
+
+impl From<i128> for BigInt {
+    fn from(value: i128) -> Self {
+        let BigInt { digits, .. } = BigInt::from(value.unsigned_abs());
+        BigInt::new(value < 0, digits)
+    }
+}
+
+macro_rules! impl_from {
+    ($($type_:ident => $via:ident),*) => {
+        $(
+            impl From<$type_> for BigInt {
+                fn from(value: $type_) -> Self {
+                    BigInt::from(value as $via)
+                }
+            }
+        )*
+    };
+}
+
+impl_from!(i8 => i128, i16 => i128, i32 => i128, i64 => i128, isize => i128);
+impl_from!(u8 => u128, u16 => u128, u32 => u128, u64 => u128, usize => u128);
+
+macro_rules! impl_try_from {
+    ($($type_:ident),*) => {
+        $(
+            impl TryFrom<&BigInt> for $type_ {
+                type Error = TryFromBigIntError;
+
+                fn try_from(value: &BigInt) -> Result<Self, Self::Error> {
+                    let value = match value.sign_magnitude() {
+                        Some((true, magnitude)) => 0i128
+                            .checked_sub_unsigned(magnitude)
+                            .and_then(|value| value.try_into().ok()),
+                        Some((false, magnitude)) => magnitude.try_into().ok(),
+                        None => None,
+                    };
+                    value.ok_or(TryFromBigIntError)
+                }
+            }
+        )*
+    };
+}
+
+impl_try_from!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+impl Ord for BigInt {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.negative, other.negative) {
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+            (false, false) => cmp_digits(&self.digits, &other.digits),
+            (true, true) => cmp_digits(&other.digits, &self.digits),
+        }
+    }
+}
+
+impl PartialOrd for BigInt {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Neg for &BigInt {
+    type Output = BigInt;
+
+    fn neg(self) -> BigInt {
+        BigInt::new(!self.negative, self.digits.clone())
+    }
+}
+
+impl Neg for BigInt {
+    type Output = BigInt;
+
+    fn neg(self) -> BigInt {
+        BigInt::new(!self.negative, self.digits)
+    }
+}
+
+impl Add for &BigInt {
+    type Output = BigInt;
+
+    fn add(self, rhs: &BigInt) -> BigInt {
+        if self.negative == rhs.negative {
+            return BigInt::new(self.negative, add_digits(&self.digits, &rhs.digits));
+        }
+
+        match cmp_digits(&self.digits, &rhs.digits) {
+            Ordering::Less => BigInt::new(rhs.negative, sub_digits(&rhs.digits, &self.digits)),
+            _ => BigInt::new(self.negative, sub_digits(&self.digits, &rhs.digits)),
+        }
+    }
+}
+
+impl Sub for &BigInt {
+    type Output = BigInt;
+
+    fn sub(self, rhs: &BigInt) -> BigInt {
+        self + &-rhs
+    }
+}
+
+impl Mul for &BigInt {
+    type Output = BigInt;
+
+    fn mul(self, rhs: &BigInt) -> BigInt {
+        BigInt::new(
+            self.negative != rhs.negative,
+            mul_digits(&self.digits, &rhs.digits),
+        )
+    }
+}
+
+/// Truncating division, like the primitive integers.
+impl Div for &BigInt {
+    type Output = BigInt;
+
+    fn div(self, rhs: &BigInt) -> BigInt {
+        assert!(!rhs.is_zero(), "attempt to divide by zero");
+        let (quotient, _) = div_rem_digits(&self.digits, &rhs.digits);
+        BigInt::new(self.negative != rhs.negative, quotient)
+    }
+}
+
+/// The remainder of truncating division, which has the sign of `self`.
+impl Rem for &BigInt {
+    type Output = BigInt;
+
+    fn rem(self, rhs: &BigInt) -> BigInt {
+        assert!(
+            !rhs.is_zero(),
+            "attempt to calculate the remainder with a divisor of zero"
+        );
+        let (_, remainder) = div_rem_digits(&self.digits, &rhs.digits);
+        BigInt::new(self.negative, remainder)
+    }
+}
+
+/// Implements an operator on owned values by forwarding to the implementation on references.
+macro_rules! forward_owned {
+    ($($trait_:ident::$method:ident),*) => {
+        $(
+            impl $trait_ for BigInt {
+                type Output = BigInt;
+
+                fn $method(self, rhs: BigInt) -> BigInt {
+                    (&self).$method(&rhs)
+                }
+            }
+
+            impl $trait_<&BigInt> for BigInt {
+                type Output = BigInt;
+
+                fn $method(self, rhs: &BigInt) -> BigInt {
+                    (&self).$method(rhs)
+                }
+            }
+        )*
+    };
+}
+
+forward_owned!(Add::add, Sub::sub, Mul::mul, Div::div, Rem::rem);
+
+impl Display for BigInt {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        const CHUNK: u32 = 1_000_000_000;
+
+        let mut chunks = Vec::new();
+        let mut rest = self.digits.clone();
+        while !rest.is_empty() {
+            let (quotient, remainder) = div_rem_small(&rest, CHUNK);
+            chunks.push(remainder);
+            rest = BigInt::new(false, quotient).digits;
+        }
+
+        let mut decimal = chunks.pop().unwrap_or(0).to_string();
+        for chunk in chunks.iter().rev() {
+            decimal.push_str(&format!("{:09}", chunk));
+        }
+        f.pad_integral(!self.negative, "", &decimal)
+    }
+}
+
+impl FromStr for BigInt {
+    type Err = ParseBigIntError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (negative, decimal) = match s.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, s.strip_prefix('+').unwrap_or(s)),
+        };
+        if decimal.is_empty() || !decimal.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(ParseBigIntError);
+        }
+
+        let mut digits = Vec::new();
+        for chunk in decimal.as_bytes().chunks(9) {
+            let value = chunk.iter().fold(0, |acc, b| acc * 10 + (b - b'0') as u32);
+            let scale = 10u32.pow(chunk.len() as u32);
+            digits = add_digits(&mul_digits(&digits, &[scale]), &[value]);
+        }
+        Ok(BigInt::new(negative, digits))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn big(s: &str) -> BigInt {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let values: [i128; 7] = [
+            0,
+            1,
+            -1,
+            7,
+            -4_294_967_296,
+            123_456_789_012_345,
+            -98_765_432_109_876_543,
+        ];
+
+        for a in values {
+            for b in values {
+                let (x, y) = (BigInt::from(a), BigInt::from(b));
+                assert_eq!(&x + &y, BigInt::from(a + b));
+                assert_eq!(&x - &y, BigInt::from(a - b));
+                assert_eq!(&x * &y, BigInt::from(a * b));
+                assert_eq!(x.cmp(&y), a.cmp(&b));
+                if b != 0 {
+                    assert_eq!(&x / &y, BigInt::from(a / b));
+                    assert_eq!(&x % &y, BigInt::from(a % b));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_large_values() {
+        let factorial = (1..=40u32).fold(BigInt::from(1), |acc, n| acc * BigInt::from(n));
+        assert_eq!(
+            factorial.to_string(),
+            "815915283247897734345611269596115894272000000000"
+        );
+        assert_eq!(factorial.sign_magnitude(), None);
+
+        let max = BigInt::from(u128::MAX);
+        let square = &max * &max;
+        assert_eq!(&square / &max, max);
+        assert_eq!(&(&square + &BigInt::from(5)) % &max, BigInt::from(5));
+        assert_eq!(BigInt::from(2).pow(128), &max + &BigInt::from(1));
+
+        assert_eq!(big("-12").gcd(&big("18")), BigInt::from(6));
+        assert_eq!(format!("{:>6}", big("-42")), "   -42");
+    }
+
+    #[test]
+    fn test_conversions() {
+        let text = "-170141183460469231731687303715884105728";
+        assert_eq!(big(text).to_string(), text);
+        assert_eq!(i128::try_from(&big(text)), Ok(i128::MIN));
+        assert_eq!(big("+0"), BigInt::default());
+        assert_eq!(big("-0").to_string(), "0");
+        assert!("12a".parse::<BigInt>().is_err());
+        assert!("-".parse::<BigInt>().is_err());
+
+        assert_eq!(u8::try_from(&BigInt::from(255)), Ok(255));
+        assert_eq!(u8::try_from(&BigInt::from(256)), Err(TryFromBigIntError));
+        assert_eq!(u64::try_from(&BigInt::from(-1)), Err(TryFromBigIntError));
+        assert_eq!(
+            usize::try_from(&BigInt::from(2).pow(200)),
+            Err(TryFromBigIntError)
+        );
+    }
+}
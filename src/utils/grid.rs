@@ -0,0 +1,214 @@
+use std::fmt::{self, Display, Formatter};
+
+use crate::utils::{
+    parse::{ParseError, Span},
+    vector_2d::{Vector2, E, N, NE, NW, S, SE, SW, W},
+};
+
+/// A rectangle of tiles, stored row by row in a single `Vec`.
+///
+/// Positions follow the convention of the puzzles that read grids: `x` grows to the right and
+/// `y` grows upwards, so the last line of the input is row 0 and `N` points to the line above.
+#[derive(Clone, Debug, Default, Hash, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    tiles: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// A `width` by `height` grid filled with `tile`.
+    pub fn new(width: usize, height: usize, tile: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            tiles: vec![tile; width * height],
+        }
+    }
+
+    /// Reads one tile per character, converting each with `tile`. Fails if a character is not a
+    /// valid tile or the lines are not all the same length.
+    pub fn parse(input: &str, tile: impl Fn(char) -> Option<T>) -> Result<Self, ParseError> {
+        let lines = Span::new(input).lines().collect::<Vec<_>>();
+        let width = lines
+            .first()
+            .map_or(0, |line| line.as_str().chars().count());
+
+        let mut tiles = Vec::with_capacity(width * lines.len());
+        for line in lines.iter().rev() {
+            let mut count = 0;
+            for (i, c) in line.as_str().char_indices() {
+                match tile(c) {
+                    Some(t) => tiles.push(t),
+                    None => return Err(line.skip(i).error(format!("invalid tile '{}'", c))),
+                }
+                count += 1;
+            }
+            if count != width {
+                return Err(line.error(format!("expected {} tiles, found {}", width, count)));
+            }
+        }
+
+        Ok(Grid {
+            width,
+            height: lines.len(),
+            tiles,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Whether `pos` lies inside the grid.
+    pub fn contains(&self, pos: Vector2) -> bool {
+        (0..self.width as isize).contains(&pos.x) && (0..self.height as isize).contains(&pos.y)
+    }
+
+    pub fn get(&self, pos: Vector2) -> Option<&T> {
+        self.index(pos).map(|i| &self.tiles[i])
+    }
+
+    pub fn get_mut(&mut self, pos: Vector2) -> Option<&mut T> {
+        self.index(pos).map(|i| &mut self.tiles[i])
+    }
+
+    /// Every position and its tile, row by row from `y = 0`.
+    pub fn iter(&self) -> impl Iterator<Item = (Vector2, &T)> {
+        self.tiles
+            .iter()
+            .enumerate()
+            .map(|(i, tile)| (self.position(i), tile))
+    }
+
+    /// The rows of the grid from `y = 0` upwards, which is the input read from the bottom.
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> {
+        // `max(1)` keeps `chunks` happy for a grid with no columns, which has no tiles anyway
+        self.tiles.chunks(self.width.max(1))
+    }
+
+    /// The columns of the grid from `x = 0`, each going from `y = 0` upwards.
+    pub fn columns(&self) -> impl DoubleEndedIterator<Item = impl DoubleEndedIterator<Item = &T>> {
+        (0..self.width).map(move |x| self.tiles[x..].iter().step_by(self.width))
+    }
+
+    /// The positions next to `pos` in the four cardinal directions that lie inside the grid.
+    pub fn neighbours4(&self, pos: Vector2) -> impl Iterator<Item = Vector2> + '_ {
+        [N, E, S, W]
+            .into_iter()
+            .map(move |dir| pos + dir)
+            .filter(|next| self.contains(*next))
+    }
+
+    /// The positions next to `pos`, diagonals included, that lie inside the grid.
+    pub fn neighbours8(&self, pos: Vector2) -> impl Iterator<Item = Vector2> + '_ {
+        [N, NE, E, SE, S, SW, W, NW]
+            .into_iter()
+            .map(move |dir| pos + dir)
+            .filter(|next| self.contains(*next))
+    }
+
+    /// The first position, row by row from `y = 0`, whose tile matches `predicate`.
+    pub fn find(&self, predicate: impl Fn(&T) -> bool) -> Option<Vector2> {
+        self.tiles
+            .iter()
+            .position(predicate)
+            .map(|i| self.position(i))
+    }
+
+    /// Draws the grid as it appeared in the input, top row first, with one character per tile.
+    pub fn render(&self, tile: impl Fn(&T) -> char) -> String {
+        self.rows()
+            .rev()
+            .map(|row| row.iter().map(&tile).collect::<String>())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    fn index(&self, pos: Vector2) -> Option<usize> {
+        self.contains(pos)
+            .then(|| pos.row_index() * self.width + pos.column_index())
+    }
+
+    fn position(&self, index: usize) -> Vector2 {
+        Vector2::new_usize(index % self.width, index / self.width)
+    }
+}
+
+/// Shows the grid as it appeared in the input, top row first.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for (i, row) in self.rows().rev().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            for tile in row {
+                write!(f, "{}", tile)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_grid() {
+        let input = "#..\n.#.\n..S";
+        let mut grid = Grid::parse(input, |c| "#.S".contains(c).then_some(c)).unwrap();
+
+        assert_eq!((grid.width(), grid.height()), (3, 3));
+        assert_eq!(grid.to_string(), input);
+        assert_eq!(grid.get(Vector2::new(0, 2)), Some(&'#'));
+        assert_eq!(grid.get(Vector2::new(2, 0)), Some(&'S'));
+        assert_eq!(grid.get(Vector2::new(3, 0)), None);
+        assert_eq!(grid.get(Vector2::new(0, -1)), None);
+        assert_eq!(grid.find(|&c| c == 'S'), Some(Vector2::new(2, 0)));
+
+        *grid.get_mut(Vector2::new(1, 0)).unwrap() = '#';
+        assert_eq!(
+            grid.render(|&c| if c == '#' { '█' } else { ' ' }),
+            "█  \n █ \n █ "
+        );
+
+        let rows = grid.rows().map(|row| row.iter().collect::<String>());
+        assert_eq!(rows.collect::<Vec<_>>(), [".#S", ".#.", "#.."]);
+        let columns = grid.columns().map(|column| column.collect::<String>());
+        assert_eq!(columns.collect::<Vec<_>>(), ["..#", "##.", "S.."]);
+
+        let mut corner = grid.neighbours4(Vector2::new(0, 0)).collect::<Vec<_>>();
+        corner.sort();
+        assert_eq!(corner, [Vector2::new(0, 1), Vector2::new(1, 0)]);
+        assert_eq!(grid.neighbours8(Vector2::new(0, 0)).count(), 3);
+        assert_eq!(grid.neighbours8(Vector2::new(1, 1)).count(), 8);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let digit = |c: char| c.to_digit(10);
+
+        let err = Grid::parse("12\n3x", digit).unwrap_err();
+        assert!(err.to_string().contains("invalid tile 'x'"), "{}", err);
+        let err = Grid::parse("12\n345", digit).unwrap_err();
+        assert!(
+            err.to_string().contains("expected 2 tiles, found 3"),
+            "{}",
+            err
+        );
+
+        let empty = Grid::parse("", digit).unwrap();
+        assert_eq!(
+            (empty.width(), empty.height(), empty.rows().count()),
+            (0, 0, 0)
+        );
+    }
+}
@@ -0,0 +1,256 @@
+use std::ops::Range;
+
+/// The letters of the 6 pixel high alphabet, drawn next to each other: most are 4 pixels wide
+/// and followed by a blank column, while `I` is 3 and `Y` is 5 pixels wide.
+const ALPHABET_6: [(&str, [&str; 6]); 2] = [
+    (
+        "ABCEFGHJKLOPRSUZ",
+        [
+            ".##..###...##..####.####..##..#..#...##.#..#.#.....##..###..###...###.#..#.####.",
+            "#..#.#..#.#..#.#....#....#..#.#..#....#.#.#..#....#..#.#..#.#..#.#....#..#....#.",
+            "#..#.###..#....###..###..#....####....#.##...#....#..#.#..#.#..#.#....#..#...#..",
+            "####.#..#.#....#....#....#.##.#..#....#.#.#..#....#..#.###..###...##..#..#..#...",
+            "#..#.#..#.#..#.#....#....#..#.#..#.#..#.#.#..#....#..#.#....#.#.....#.#..#.#....",
+            "#..#.###...##..####.#.....###.#..#..##..#..#.####..##..#....#..#.###...##..####.",
+        ],
+    ),
+    (
+        "IY",
+        [
+            "###...#...#.",
+            ".#....#...#.",
+            ".#.....#.#..",
+            ".#......#...",
+            ".#......#...",
+            "###.....#...",
+        ],
+    ),
+];
+
+/// The letters of the 10 pixel high alphabet, drawn next to each other: each is 6 pixels wide
+/// and followed by two blank columns.
+const ALPHABET_10: [(&str, [&str; 10]); 2] = [
+    (
+        "ABCEFGHJ",
+        [
+            "..##....#####....####...######..######...####...#....#.....###..",
+            ".#..#...#....#..#....#..#.......#.......#....#..#....#......#...",
+            "#....#..#....#..#.......#.......#.......#.......#....#......#...",
+            "#....#..#....#..#.......#.......#.......#.......#....#......#...",
+            "#....#..#####...#.......#####...#####...#.......######......#...",
+            "######..#....#..#.......#.......#.......#..###..#....#......#...",
+            "#....#..#....#..#.......#.......#.......#....#..#....#......#...",
+            "#....#..#....#..#.......#.......#.......#....#..#....#..#...#...",
+            "#....#..#....#..#....#..#.......#.......#...##..#....#..#...#...",
+            "#....#..#####....####...######..#........###.#..#....#...###....",
+        ],
+    ),
+    (
+        "KLNPRXZ",
+        [
+            "#....#..#.......#....#..#####...#####...#....#..######..",
+            "#...#...#.......##...#..#....#..#....#..#....#.......#..",
+            "#..#....#.......##...#..#....#..#....#...#..#........#..",
+            "#.#.....#.......#.#..#..#....#..#....#...#..#.......#...",
+            "##......#.......#.#..#..#####...#####.....##.......#....",
+            "##......#.......#..#.#..#.......#..#......##......#.....",
+            "#.#.....#.......#..#.#..#.......#...#....#..#....#......",
+            "#..#....#.......#...##..#.......#...#....#..#...#.......",
+            "#...#...#.......#...##..#.......#....#..#....#..#.......",
+            "#....#..######..#....#..#.......#....#..#....#..######..",
+        ],
+    ),
+];
+
+/// Draws a `width` by `height` picture, with `#` for the pixels where `lit` is true and `.`
+/// for the others, one line per row.
+pub fn render(width: usize, height: usize, lit: impl Fn(usize, usize) -> bool) -> String {
+    (0..height)
+        .map(|y| {
+            (0..width)
+                .map(|x| if lit(x, y) { '#' } else { '.' })
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Reads the letters drawn in `art`, where `#` and `█` are lit pixels and anything else is
+/// blank. Letters are told apart by the blank columns between them, or split where they touch,
+/// and the alphabet is picked by the height of the picture, ignoring blank rows around it.
+/// Returns `None` if the height matches neither alphabet or a letter is not recognised.
+pub fn decode(art: &str) -> Option<String> {
+    let rows = art
+        .lines()
+        .map(|line| {
+            line.chars()
+                .map(|c| c == '#' || c == '█')
+                .collect::<Vec<_>>()
+        })
+        .skip_while(|row| !row.contains(&true))
+        .collect::<Vec<_>>();
+    let end = rows.iter().rposition(|row| row.contains(&true))? + 1;
+    let rows = &rows[..end];
+
+    let width = rows.iter().map(Vec::len).max().unwrap_or(0);
+    let lit = |x: usize, y: usize| rows[y].get(x).copied().unwrap_or(false);
+    let blank_column = |x: usize| (0..rows.len()).all(|y| !lit(x, y));
+
+    let mut text = String::new();
+    let mut x = 0;
+    while x < width {
+        if blank_column(x) {
+            x += 1;
+            continue;
+        }
+
+        let start = x;
+        while x < width && !blank_column(x) {
+            x += 1;
+        }
+        text.push_str(&read_touching(start..x, rows.len(), &lit)?);
+    }
+
+    Some(text)
+}
+
+/// Reads the letters in `columns`, which have no blank column between them. A letter as wide as
+/// its cell, like the `Y` of the 6 pixel alphabet, touches the next one, so if the columns are
+/// not a single letter they are split after the first one that is.
+fn read_touching(
+    columns: Range<usize>,
+    height: usize,
+    lit: &impl Fn(usize, usize) -> bool,
+) -> Option<String> {
+    let glyph =
+        |columns: Range<usize>| render(columns.len(), height, |dx, y| lit(columns.start + dx, y));
+    if let Some(letter) = recognise(&glyph(columns.clone())) {
+        return Some(letter.to_string());
+    }
+
+    (columns.start + 1..columns.end).find_map(|split| {
+        let first = recognise(&glyph(columns.start..split))?;
+        let rest = read_touching(split..columns.end, height, lit)?;
+        Some(format!("{}{}", first, rest))
+    })
+}
+
+/// The letter drawn in `glyph`, which is trimmed to its lit columns.
+fn recognise(glyph: &str) -> Option<char> {
+    let mut letters: Box<dyn Iterator<Item = (char, String)>> = match glyph.lines().count() {
+        6 => Box::new(letters(&ALPHABET_6)),
+        10 => Box::new(letters(&ALPHABET_10)),
+        _ => return None,
+    };
+
+    letters
+        .find(|(_, art)| trim_columns(art) == glyph)
+        .map(|(letter, _)| letter)
+}
+
+/// Every letter of an alphabet and its picture, including the blank columns after it.
+fn letters<const H: usize>(
+    alphabet: &'static [(&str, [&str; H])],
+) -> impl Iterator<Item = (char, String)> {
+    alphabet.iter().flat_map(|(letters, rows)| {
+        let width = rows[0].len() / letters.len();
+        letters.chars().enumerate().map(move |(i, letter)| {
+            let art = render(width, H, |x, y| rows[y].as_bytes()[i * width + x] == b'#');
+            (letter, art)
+        })
+    })
+}
+
+/// Removes the blank columns on both sides of a rendered picture.
+fn trim_columns(art: &str) -> String {
+    let rows = art.lines().map(str::as_bytes).collect::<Vec<_>>();
+    let width = rows[0].len();
+    let lit_column = |x: usize| rows.iter().any(|row| row[x] == b'#');
+
+    let Some(start) = (0..width).find(|&x| lit_column(x)) else {
+        return String::new();
+    };
+    let end = (0..width).rfind(|&x| lit_column(x)).unwrap() + 1;
+    render(end - start, rows.len(), |x, y| rows[y][start + x] == b'#')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Draws `text` in one of the alphabets.
+    fn draw<const H: usize>(alphabet: &'static [(&str, [&str; H])], text: &str) -> String {
+        let pictures = text
+            .chars()
+            .map(|c| {
+                letters(alphabet)
+                    .find(|(letter, _)| *letter == c)
+                    .unwrap()
+                    .1
+            })
+            .collect::<Vec<_>>();
+
+        (0..H)
+            .map(|y| {
+                pictures
+                    .iter()
+                    .map(|art| art.lines().nth(y).unwrap())
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    #[test]
+    fn test_decode() {
+        let art = draw(&ALPHABET_6, "ZUJEHKGC");
+        assert_eq!(decode(&art).as_deref(), Some("ZUJEHKGC"));
+        let art = draw(&ALPHABET_6, "PIYSOF");
+        assert_eq!(decode(&art).as_deref(), Some("PIYSOF"));
+
+        let art = draw(&ALPHABET_10, "XPNBAFLR");
+        assert_eq!(decode(&art).as_deref(), Some("XPNBAFLR"));
+
+        // Blank borders and other pixel characters are accepted
+        let art = draw(&ALPHABET_6, "AB").replace('#', "█").replace('.', " ");
+        assert_eq!(decode(&format!("\n{}\n", art)).as_deref(), Some("AB"));
+        assert_eq!(
+            decode(&format!(
+                "\n  {}\n",
+                draw(&ALPHABET_6, "OR").replace('\n', "\n  ")
+            ))
+            .as_deref(),
+            Some("OR")
+        );
+
+        // Laid out like the image of 2019 day 8: five pixel cells, so `Y` touches the `L`
+        let image = "\
+#...##....####.###....##.
+#...##....#....#..#....#.
+.#.#.#....###..#..#....#.
+..#..#....#....###.....#.
+..#..#....#....#....#..#.
+..#..####.#....#.....##..";
+        assert_eq!(decode(image).as_deref(), Some("YLFPJ"));
+
+        // Unlit pixels printed as spaces, with trailing blank columns
+        let screen = [
+            "###  #### #  #  ##  ###    ##  ",
+            "#  # #    #  # #  # #  #    #  ",
+            "#  # ###  #### #  # #  #    #  ",
+            "###  #    #  # #  # ###     #  ",
+            "#    #    #  # #  # # #  #  #  ",
+            "#    #### #  #  ##  #  #  ##   ",
+        ];
+        assert_eq!(decode(&screen.join("\n")).as_deref(), Some("PEHORJ"));
+
+        assert_eq!(decode("#.#\n.#.\n#.#"), None);
+        assert_eq!(decode(""), None);
+    }
+
+    #[test]
+    fn test_render() {
+        let art = render(4, 2, |x, y| (x + y) % 2 == 0);
+        assert_eq!(art, "#.#.\n.#.#");
+    }
+}
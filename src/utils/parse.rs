@@ -0,0 +1,250 @@
+use std::{
+    fmt::{Display, Formatter},
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+/// Where a `ParseError` happened: a 1-based line and column, and the text of that line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Location {
+    pub line: usize,
+    pub column: usize,
+    pub snippet: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub message: String,
+    pub location: Option<Location>,
+    pub path: Option<PathBuf>,
+}
+
+impl ParseError {
+    /// An error about the input as a whole, without a location.
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            location: None,
+            path: None,
+        }
+    }
+
+    pub fn with_path(self, path: &Path) -> Self {
+        Self {
+            path: Some(path.to_path_buf()),
+            ..self
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if let Some(path) = &self.path {
+            write!(f, "{}:", path.display())?;
+        }
+
+        match &self.location {
+            Some(location) => {
+                let snippet = location.snippet.trim_end();
+                let caret = " ".repeat(location.column - 1);
+                write!(
+                    f,
+                    "{}:{}: {}\n    {}\n    {}^",
+                    location.line, location.column, self.message, snippet, caret
+                )
+            }
+            None if self.path.is_some() => write!(f, " {}", self.message),
+            None => write!(f, "{}", self.message),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// A piece of the input that remembers where it came from, so that errors can point at it.
+///
+/// It mirrors the `str` methods the parsers use (`lines`, `split`, `split_once`, `trim`, ...),
+/// except that anything that can fail returns a `ParseError` located at the offending text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Span<'a> {
+    source: &'a str,
+    text: &'a str,
+}
+
+impl<'a> Span<'a> {
+    pub fn new(source: &'a str) -> Self {
+        Self {
+            source,
+            text: source,
+        }
+    }
+
+    pub fn as_str(&self) -> &'a str {
+        self.text
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.text.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.text.len()
+    }
+
+    pub fn lines(self) -> impl DoubleEndedIterator<Item = Span<'a>> {
+        self.text.lines().map(move |line| self.sub(line))
+    }
+
+    pub fn split(self, pattern: &'a str) -> impl Iterator<Item = Span<'a>> {
+        self.text.split(pattern).map(move |part| self.sub(part))
+    }
+
+    pub fn split_whitespace(self) -> impl DoubleEndedIterator<Item = Span<'a>> {
+        self.text.split_whitespace().map(move |part| self.sub(part))
+    }
+
+    /// Like `str::split_once`, but a missing separator is an error at the end of the span.
+    pub fn split_once(self, separator: &str) -> Result<(Span<'a>, Span<'a>), ParseError> {
+        match self.text.split_once(separator) {
+            Some((left, right)) => Ok((self.sub(left), self.sub(right))),
+            None => Err(self
+                .skip(self.len())
+                .error(format!("expected '{}'", separator.escape_debug()))),
+        }
+    }
+
+    /// Splits off the first `mid` bytes, e.g. a one character token.
+    pub fn split_at(self, mid: usize) -> Result<(Span<'a>, Span<'a>), ParseError> {
+        match self.text.split_at_checked(mid) {
+            Some((left, right)) => Ok((self.sub(left), self.sub(right))),
+            None => Err(self.skip(self.len()).error("unexpected end of input")),
+        }
+    }
+
+    /// The rest of the span after its first `n` bytes.
+    pub fn skip(self, n: usize) -> Span<'a> {
+        self.sub(&self.text[n..])
+    }
+
+    pub fn trim(self) -> Span<'a> {
+        self.sub(self.text.trim())
+    }
+
+    pub fn strip_prefix(self, prefix: &str) -> Result<Span<'a>, ParseError> {
+        self.text
+            .strip_prefix(prefix)
+            .map(|rest| self.sub(rest))
+            .ok_or_else(|| self.error(format!("expected '{}'", prefix)))
+    }
+
+    pub fn strip_suffix(self, suffix: &str) -> Result<Span<'a>, ParseError> {
+        match self.text.strip_suffix(suffix) {
+            Some(rest) => Ok(self.sub(rest)),
+            None => Err(self
+                .skip(self.len())
+                .error(format!("expected '{}'", suffix))),
+        }
+    }
+
+    /// Parses the whole span, e.g. `span.parse::<usize>("a number")`.
+    pub fn parse<T: FromStr>(self, expected: &str) -> Result<T, ParseError> {
+        self.text
+            .parse()
+            .map_err(|_| self.error(format!("expected {}", expected)))
+    }
+
+    /// Maps the span to the value of the option it matches exactly.
+    pub fn one_of<T: Copy>(self, options: &[(&str, T)]) -> Result<T, ParseError> {
+        if let Some((_, value)) = options.iter().find(|(option, _)| *option == self.text) {
+            return Ok(*value);
+        }
+
+        let names = options
+            .iter()
+            .map(|(option, _)| format!("'{}'", option))
+            .collect::<Vec<_>>();
+        let expected = match names.as_slice() {
+            [] => String::from("nothing"),
+            [name] => name.clone(),
+            [init @ .., last] => format!("{} or {}", init.join(", "), last),
+        };
+
+        Err(self.error(format!("expected {}", expected)))
+    }
+
+    /// An error located at the start of the span.
+    pub fn error(&self, message: impl Into<String>) -> ParseError {
+        let offset = self.offset();
+        let line_start = self.source[..offset].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = self.source[offset..]
+            .find('\n')
+            .map_or(self.source.len(), |i| offset + i);
+
+        ParseError {
+            message: message.into(),
+            location: Some(Location {
+                line: self.source[..offset].matches('\n').count() + 1,
+                column: self.source[line_start..offset].chars().count() + 1,
+                snippet: self.source[line_start..line_end].to_string(),
+            }),
+            path: None,
+        }
+    }
+
+    fn offset(&self) -> usize {
+        self.text.as_ptr() as usize - self.source.as_ptr() as usize
+    }
+
+    /// Wraps `text`, which must be a slice of this span.
+    fn sub(&self, text: &'a str) -> Span<'a> {
+        Span {
+            source: self.source,
+            text,
+        }
+    }
+}
+
+impl Display for Span<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.text.fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_locations() {
+        let input = Span::new("px{a<2006:qkq}\nin{s!1351:px}\n");
+        let line = input.lines().nth(1).unwrap();
+        let (_, rules) = line.split_once("{").unwrap();
+        let (comparison, _) = rules.skip(1).split_at(1).unwrap();
+
+        let err = comparison
+            .one_of(&[("<", 0), (">", 1)])
+            .unwrap_err()
+            .with_path(Path::new("input/2023/day19/real.txt"));
+
+        assert_eq!(
+            err.to_string(),
+            "input/2023/day19/real.txt:2:5: expected '<' or '>'\n    in{s!1351:px}\n        ^"
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        let line = Span::new("1,2\n3 x");
+        let (first, second) = line.lines().last().unwrap().split_once(" ").unwrap();
+
+        assert_eq!(first.parse::<u8>("a number"), Ok(3));
+        let err = second.parse::<u8>("a number").unwrap_err();
+        assert_eq!(err.location.map(|l| (l.line, l.column)), Some((2, 3)));
+
+        let err = line.split_once("~").unwrap_err();
+        assert_eq!(err.message, "expected '~'");
+        assert_eq!(err.location.map(|l| (l.line, l.column)), Some((2, 4)));
+
+        assert_eq!(ParseError::new("empty input").to_string(), "empty input");
+    }
+}
@@ -15,6 +15,7 @@ pub const SE: Vector2 = Vector2 { x: 1, y: -1 };
 pub const NW: Vector2 = Vector2 { x: -1, y: 1 };
 
 #[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Vector2 {
     pub x: isize,
     pub y: isize,
@@ -27,7 +28,7 @@ impl Display for Vector2 {
 }
 
 impl Vector2 {
-    pub(crate) fn new(x: isize, y: isize) -> Vector2 {
+    pub fn new(x: isize, y: isize) -> Vector2 {
         Vector2 { x, y }
     }
 
@@ -113,17 +114,17 @@ impl Vector2 {
         *self + W
     }
 
-    pub(crate) fn magnitude(&self) -> usize {
+    pub fn magnitude(&self) -> usize {
         self.x.unsigned_abs().max(self.y.unsigned_abs())
     }
 
-    pub(crate) fn wrap(&self, max_x: usize, max_y: usize) -> Vector2 {
+    pub fn wrap(&self, max_x: usize, max_y: usize) -> Vector2 {
         let x = self.x.rem_euclid(max_x as isize);
         let y = self.y.rem_euclid(max_y as isize);
         Vector2 { x, y }
     }
 
-    pub(crate) fn cross(&self, vel_b: Vector2) -> Vector2 {
+    pub fn cross(&self, vel_b: Vector2) -> Vector2 {
         Vector2 {
             x: self.y * vel_b.y - self.x * vel_b.x,
             y: self.x * vel_b.y - self.y * vel_b.x,
@@ -272,3 +273,19 @@ impl std::ops::Mul<f64> for Vector2 {
         (self.x as f64 * rhs, self.y as f64 * rhs)
     }
 }
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_serde() {
+        let points = [Vector2::new(3, -4), NW, Vector2::default()];
+
+        for point in points {
+            let json = serde_json::to_string(&point).unwrap();
+            assert_eq!(serde_json::from_str::<Vector2>(&json).unwrap(), point);
+        }
+        assert_eq!(serde_json::to_string(&NW).unwrap(), r#"{"x":-1,"y":1}"#);
+    }
+}
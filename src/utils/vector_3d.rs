@@ -3,6 +3,7 @@
 use std::ops::{Add, AddAssign, Sub, SubAssign};
 
 #[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Vector3 {
     pub x: f64,
     pub y: f64,
@@ -50,7 +51,7 @@ impl Vector3 {
         ((self.x - other.x).abs() + (self.y - other.y).abs() + (self.z - other.z).abs()) as usize
     }
 
-    pub(crate) fn cross(&self, vel_b: Vector3) -> Vector3 {
+    pub fn cross(&self, vel_b: Vector3) -> Vector3 {
         let a = self;
         let b = vel_b;
 
@@ -61,7 +62,7 @@ impl Vector3 {
         }
     }
 
-    pub(crate) fn normalize(&self) -> Vector3 {
+    pub fn normalize(&self) -> Vector3 {
         let mag = self.magnitude();
         Vector3 {
             x: self.x / mag,
@@ -70,15 +71,12 @@ impl Vector3 {
         }
     }
 
-    pub(crate) fn dot(&self, normal: Vector3) -> f64 {
+    pub fn dot(&self, normal: Vector3) -> f64 {
         self.x * normal.x + self.y * normal.y + self.z * normal.z
     }
 
     fn magnitude(&self) -> f64 {
-        (self.x * self.x
-            + self.y * self.y
-            + self.z * self.z)
-            .sqrt()
+        (self.x * self.x + self.y * self.y + self.z * self.z).sqrt()
     }
 }
 
@@ -201,3 +199,26 @@ impl std::ops::Mul<f64> for Vector3 {
         }
     }
 }
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_serde() {
+        let points = [
+            Vector3::new(0.5, -2.0, 1e20),
+            Vector3::new_isize(19, 13, 30),
+            Vector3::default(),
+        ];
+
+        for point in points {
+            let json = serde_json::to_string(&point).unwrap();
+            assert_eq!(serde_json::from_str::<Vector3>(&json).unwrap(), point);
+        }
+        assert_eq!(
+            serde_json::to_string(&Vector3::new(1.0, 2.5, -3.0)).unwrap(),
+            r#"{"x":1.0,"y":2.5,"z":-3.0}"#
+        );
+    }
+}
@@ -0,0 +1,92 @@
+use crate::{Solution, SolutionPair, SolveError, Timer};
+
+pub type Solver = fn(&str, &mut Timer) -> Result<SolutionPair, SolveError>;
+
+/// A registered day: its solver plus what the runner needs to know about it.
+#[derive(Debug, Clone, Copy)]
+pub struct Day {
+    pub number: u8,
+    pub title: &'static str,
+    /// Whether part 1 and part 2 are solved.
+    pub implemented: [bool; 2],
+    /// What `solve` returns for part 1 and part 2 of the example input, if known.
+    pub samples: [Option<&'static str>; 2],
+    pub solve: Solver,
+}
+
+impl Day {
+    /// The sample answer of `part` as a `Solution`, or `Solution::None` if it is not known.
+    pub fn sample(&self, part: u8) -> Solution {
+        match self.samples[usize::from(part) - 1] {
+            Some(sample) => {
+                let Ok(solution) = sample.parse();
+                solution
+            }
+            None => Solution::None,
+        }
+    }
+}
+
+/// A registered event and its days.
+#[derive(Debug, Clone, Copy)]
+pub struct Year {
+    pub number: u16,
+    pub days: &'static [Day],
+}
+
+impl Year {
+    pub fn get(&self, day: u8) -> Option<&'static Day> {
+        self.days.iter().find(|d| d.number == day)
+    }
+}
+
+/// Declares the day modules of a year and collects their `DAY` constants into `REGISTRY`.
+macro_rules! days {
+    ($($module:ident),* $(,)?) => {
+        $(pub mod $module;)*
+
+        pub const REGISTRY: &[Day] = &[$($module::DAY),*];
+    };
+}
+
+/// Declares the year modules and collects their `YEAR` constants into `YEARS`.
+macro_rules! years {
+    ($($module:ident),* $(,)?) => {
+        $(pub mod $module;)*
+
+        pub const YEARS: &[Year] = &[$($module::YEAR),*];
+    };
+}
+
+years![y2023];
+
+pub fn get(year: u16) -> Option<&'static Year> {
+    YEARS.iter().find(|y| y.number == year)
+}
+
+/// The most recent registered year, which the runner uses when `--year` is not given.
+pub fn latest() -> &'static Year {
+    YEARS.iter().max_by_key(|y| y.number).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_registry() {
+        assert!(YEARS.windows(2).all(|w| w[0].number < w[1].number));
+        for year in YEARS {
+            assert!(year.days.windows(2).all(|w| w[0].number < w[1].number));
+        }
+
+        let year = get(2023).unwrap();
+        assert_eq!(year.get(7).map(|day| day.title), Some("Camel Cards"));
+        assert!(year.get(26).is_none());
+        assert!(get(2015).is_none());
+
+        let day = year.get(18).unwrap();
+        assert_eq!(day.sample(2), Solution::Usize(952_408_144_115));
+        assert_eq!(year.get(21).unwrap().sample(1), Solution::None);
+    }
+}
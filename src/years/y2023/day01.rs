@@ -0,0 +1,60 @@
+use crate::{
+    utils::parse::{ParseError, Span},
+    years::Day,
+    Solution, SolutionPair, SolveError, Timer,
+};
+
+pub const DAY: Day = Day {
+    number: 1,
+    title: "Trebuchet?!",
+    implemented: [true, true],
+    samples: [Some("142"), Some("281")],
+    solve,
+};
+
+pub fn solve(input: &str, timer: &mut Timer) -> Result<SolutionPair, SolveError> {
+    Ok((timer.part1(|| p1(input))?, timer.part2(|| p2(input))?))
+}
+
+fn get_digits(line: Span) -> Result<u32, ParseError> {
+    let mut chars = line.as_str().chars().filter_map(|c| c.to_digit(10));
+
+    let first_digit = chars.next().ok_or_else(|| line.error("expected a digit"))?;
+    let last_digit = chars.last().unwrap_or(first_digit);
+
+    Ok(10 * first_digit + last_digit)
+}
+
+fn p1(input: &str) -> Result<Solution, SolveError> {
+    Ok(Span::new(input).lines().map(get_digits).sum::<Result<u32, _>>()?.into())
+}
+
+fn p2(input: &str) -> Result<Solution, SolveError> {
+    // Every word keeps its length, so errors still point at the right column of the input
+    let input = input
+        .replace("one", "o1e")
+        .replace("two", "t2o")
+        .replace("three", "t3e")
+        .replace("four", "f4r")
+        .replace("five", "f5e")
+        .replace("six", "s6x")
+        .replace("seven", "s7n")
+        .replace("eight", "e8t")
+        .replace("nine", "n9e");
+    Ok(Span::new(&input).lines().map(get_digits).sum::<Result<u32, _>>()?.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::etc::Solution;
+
+    #[test]
+    fn test_sample_input() {
+        let p1_input = include_str!("../../../input/2023/day01/test_p1.txt");
+        let p2_input = include_str!("../../../input/2023/day01/test_p2.txt");
+        let (p1, p2) = (super::p1(p1_input).unwrap(), super::p2(p2_input).unwrap());
+
+        assert_eq!(p1, Solution::U32(142));
+        assert_eq!(p2, Solution::U32(281));
+    }
+}
@@ -0,0 +1,98 @@
+use crate::{
+    utils::parse::{ParseError, Span},
+    years::Day,
+    Solution, SolutionPair, SolveError, Timer,
+};
+
+#[derive(Debug, PartialEq, Default)]
+struct Game {
+    id: usize,
+    red: usize,
+    green: usize,
+    blue: usize,
+}
+impl Game {
+    fn new(id: usize) -> Game {
+        Game {
+            id: id + 1,
+            red: 0,
+            green: 0,
+            blue: 0,
+        }
+    }
+
+    fn power(&self) -> usize {
+        self.red * self.green * self.blue
+    }
+}
+
+pub const DAY: Day = Day {
+    number: 2,
+    title: "Cube Conundrum",
+    implemented: [true, true],
+    samples: [Some("8"), Some("2286")],
+    solve,
+};
+
+pub fn solve(input: &str, timer: &mut Timer) -> Result<SolutionPair, SolveError> {
+    let games = timer.parse(|| parse_games(input))?;
+
+    Ok((
+        timer.part1(|| Solution::Usize(p1(&games)))?,
+        timer.part2(|| Solution::Usize(p2(&games)))?,
+    ))
+}
+
+fn parse_games(input: &str) -> Result<Vec<Game>, ParseError> {
+    fn parse_game(id: usize, line: Span) -> Result<Game, ParseError> {
+        let mut game = Game::new(id);
+        let (_, sets) = line.split_once(": ")?;
+
+        for cubes in sets.split("; ").flat_map(|set| set.split(", ")) {
+            let (count, color) = cubes.trim().split_once(" ")?;
+            let count = count.parse::<usize>("a number")?;
+
+            let max = match color.as_str() {
+                "red" => &mut game.red,
+                "blue" => &mut game.blue,
+                "green" => &mut game.green,
+                _ => return Err(color.error("expected 'red', 'green' or 'blue'")),
+            };
+            *max = (*max).max(count);
+        }
+
+        Ok(game)
+    }
+
+    Span::new(input)
+        .lines()
+        .enumerate()
+        .map(|(i, line)| parse_game(i, line))
+        .collect()
+}
+
+fn p1(games: &[Game]) -> usize {
+    games
+        .iter()
+        .filter(|game| game.red <= 12 && game.green <= 13 && game.blue <= 14)
+        .map(|g| g.id)
+        .sum()
+}
+
+fn p2(games: &[Game]) -> usize {
+    games.iter().map(Game::power).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::etc::{Solution, Timer};
+
+    #[test]
+    fn test_sample_input() {
+        let input = include_str!("../../../input/2023/day02/test.txt");
+        let (p1, p2) = super::solve(input, &mut Timer::default()).unwrap();
+
+        assert_eq!(p1, Solution::Usize(8));
+        assert_eq!(p2, Solution::Usize(2286));
+    }
+}
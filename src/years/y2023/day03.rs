@@ -2,7 +2,15 @@ use std::{collections::HashMap, ops::RangeInclusive};
 
 use regex::Regex;
 
-use crate::{utils::vector_2d::Vector2, Solution, SolutionPair};
+use crate::{
+    trace,
+    utils::{
+        parse::{ParseError, Span},
+        vector_2d::Vector2,
+    },
+    years::Day,
+    Solution, SolutionPair, SolveError, Timer,
+};
 
 #[derive(Debug, Default)]
 struct Grid {
@@ -49,10 +57,22 @@ enum Entity {
     Symbol(char),
 }
 
-pub fn solve(input: &str) -> SolutionPair {
-    let grid = parse_grid(input);
-
-    (Solution::Usize(p1(&grid)), Solution::Usize(p2(&grid)))
+pub const DAY: Day = Day {
+    number: 3,
+    title: "Gear Ratios",
+    implemented: [true, true],
+    samples: [Some("4361"), Some("467835")],
+    solve,
+};
+
+pub fn solve(input: &str, timer: &mut Timer) -> Result<SolutionPair, SolveError> {
+    let grid = timer.parse(|| parse_grid(input))?;
+    trace!("{}", render_grid(&grid));
+
+    Ok((
+        timer.part1(|| Solution::Usize(p1(&grid)))?,
+        timer.part2(|| Solution::Usize(p2(&grid)))?,
+    ))
 }
 
 fn p1(grid: &Grid) -> usize {
@@ -88,16 +108,16 @@ fn p2(grid: &Grid) -> usize {
         .sum()
 }
 
-fn parse_grid(input: &str) -> Grid {
+fn parse_grid(input: &str) -> Result<Grid, ParseError> {
     let mut grid = input
         .lines()
         .enumerate()
         .fold(Grid::default(), |mut grid, (y, line)| {
             line.chars().enumerate().for_each(|(x, c)| {
                 let pos = Vector2::new_usize(x, y);
-                let entity = match c {
-                    '0'..='9' => Entity::Digit(c.to_digit(10).unwrap() as usize),
-                    '.' => Entity::Dot,
+                let entity = match (c, c.to_digit(10)) {
+                    (_, Some(digit)) => Entity::Digit(digit as usize),
+                    ('.', None) => Entity::Dot,
                     _ => Entity::Symbol(c),
                 };
 
@@ -109,33 +129,30 @@ fn parse_grid(input: &str) -> Grid {
             grid
         });
 
-    grid.part_numbers = find_numbers(input)
+    grid.part_numbers = find_numbers(input)?
         .into_iter()
         .filter(|(position, number)| should_keep_number(&grid, position, number))
         .collect::<Vec<_>>();
 
-    grid
+    Ok(grid)
 }
 
-fn find_numbers(input: &str) -> Vec<(Vector2, Number)> {
+fn find_numbers(input: &str) -> Result<Vec<(Vector2, Number)>, ParseError> {
     let re = Regex::new(r"\d+").unwrap();
 
-    input
-        .lines()
-        .enumerate()
-        .flat_map(|(y, line)| {
-            re.find_iter(line).map(move |m| {
-                let position = Vector2::new_usize(m.start(), y);
-                (
-                    position,
-                    Number(
-                        Value(m.as_str().parse().unwrap()),
-                        Width(m.end() - m.start()),
-                    ),
-                )
-            })
-        })
-        .collect()
+    let mut numbers = Vec::new();
+    for (y, line) in Span::new(input).lines().enumerate() {
+        for m in re.find_iter(line.as_str()) {
+            let position = Vector2::new_usize(m.start(), y);
+            let (digits, _) = line.skip(m.start()).split_at(m.len())?;
+            numbers.push((
+                position,
+                Number(Value(digits.parse("a number")?), Width(m.len())),
+            ));
+        }
+    }
+
+    Ok(numbers)
 }
 
 fn should_keep_number(grid: &Grid, position: &Vector2, number: &Number) -> bool {
@@ -155,30 +172,32 @@ fn should_keep_number(grid: &Grid, position: &Vector2, number: &Number) -> bool
     false
 }
 
-fn print_grid(grid: &Grid) {
+fn render_grid(grid: &Grid) -> String {
+    let mut out = String::new();
     for y in 0..=grid.height {
         for x in 0..=grid.width {
             let pos = Vector2::new_usize(x, y);
             if let Some(n) = grid.map.get(&pos) {
                 match n {
-                    Entity::Digit(n) => print!("{}", n),
-                    Entity::Dot => print!("."),
-                    Entity::Symbol(c) => print!("{}", c),
+                    Entity::Digit(n) => out.push_str(&n.to_string()),
+                    Entity::Dot => out.push('.'),
+                    Entity::Symbol(c) => out.push(*c),
                 }
             }
         }
-        println!();
+        out.push('\n');
     }
+    out
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::etc::Solution;
+    use crate::etc::{Solution, Timer};
 
     #[test]
     fn test_sample_input() {
-        let input = include_str!("../../input/day03/test.txt");
-        let (p1, p2) = super::solve(input);
+        let input = include_str!("../../../input/2023/day03/test.txt");
+        let (p1, p2) = super::solve(input, &mut Timer::default()).unwrap();
 
         assert_eq!(p1, Solution::Usize(4361));
         assert_eq!(p2, Solution::Usize(467835));
@@ -0,0 +1,96 @@
+use crate::{
+    utils::parse::{ParseError, Span},
+    years::Day,
+    Solution, SolutionPair, SolveError, Timer,
+};
+
+pub const DAY: Day = Day {
+    number: 4,
+    title: "Scratchcards",
+    implemented: [true, true],
+    samples: [Some("13"), Some("30")],
+    solve,
+};
+
+pub fn solve(input: &str, timer: &mut Timer) -> Result<SolutionPair, SolveError> {
+    let matches = timer.parse(|| parse_input(input))?;
+
+    Ok((timer.part1(|| p1(&matches))?, timer.part2(|| p2(&matches))?))
+}
+
+fn parse_input(input: &str) -> Result<Vec<usize>, ParseError> {
+    Span::new(input)
+        .lines()
+        .map(|line| {
+            let (_, card) = line.split_once(": ")?;
+            let (winning_numbers, actual) = card.split_once(" | ")?;
+
+            // hashset replacement
+            let mut wins = [false; 100];
+
+            for number in winning_numbers.split_whitespace() {
+                wins[parse_number(number)?] = true;
+            }
+
+            let actual = actual
+                .split_whitespace()
+                .map(parse_number)
+                .collect::<Result<Vec<_>, _>>()?;
+            Ok(actual.into_iter().filter(|n| wins[*n]).count())
+        })
+        .collect()
+}
+
+/// A number on a card, which has to fit in the table of winning numbers.
+fn parse_number(number: Span) -> Result<usize, ParseError> {
+    match number.parse("a number")? {
+        n @ 0..100 => Ok(n),
+        _ => Err(number.error("expected a number below 100")),
+    }
+}
+
+fn p1(matches: &[usize]) -> Result<Solution, SolveError> {
+    let mut answer = 0_usize;
+    for &n in matches {
+        let points = 1_usize
+            .checked_shl(n as u32)
+            .ok_or_else(|| SolveError::Logic(format!("{} matches overflow the points", n)))?;
+        answer += points >> 1;
+    }
+
+    Ok(Solution::Usize(answer))
+}
+
+fn p2(matches: &Vec<usize>) -> Result<Solution, SolveError> {
+    let mut copies = vec![1; matches.len()];
+
+    for (i, &n) in matches.iter().enumerate() {
+        if i + n >= copies.len() {
+            return Err(SolveError::Logic(format!(
+                "card {} wins copies of cards past the end of the table",
+                i + 1
+            )));
+        }
+
+        for j in 1..=n {
+            copies[i + j] += copies[i];
+        }
+    }
+
+    let total = copies.iter().sum();
+
+    Ok(Solution::Usize(total))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::etc::{Solution, Timer};
+
+    #[test]
+    fn test_sample_input() {
+        let input = include_str!("../../../input/2023/day04/test.txt");
+        let (p1, p2) = super::solve(input, &mut Timer::default()).unwrap();
+        assert_eq!(p1, Solution::Usize(13));
+        assert_eq!(p2, Solution::Usize(30));
+    }
+}
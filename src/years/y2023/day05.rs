@@ -1,6 +1,11 @@
 use std::ops::Range;
 
-use crate::{SolutionPair, etc::Solution};
+use crate::{
+    etc::Solution,
+    utils::parse::{ParseError, Span},
+    years::Day,
+    SolutionPair, SolveError, Timer,
+};
 
 type RangeMap = (Range<usize>, Range<usize>);
 
@@ -33,12 +38,21 @@ impl Maps {
     }
 }
 
-pub fn solve(_input: &str) -> SolutionPair {
-    (Solution::None, Solution::None)
-    // (p1::solve(input), p2::solve(input))
+pub const DAY: Day = Day {
+    number: 5,
+    title: "If You Give A Seed A Fertilizer",
+    implemented: [false, false],
+    samples: [None, None],
+    solve,
+};
+
+pub fn solve(_input: &str, _timer: &mut Timer) -> Result<SolutionPair, SolveError> {
+    Ok((Solution::None, Solution::None))
+    // let maps = timer.parse(|| parse_maps(input))?;
+    // Ok((timer.part1(|| p1::solve(input, &maps))?, timer.part2(|| p2::solve(input, &maps))?))
 }
 
-fn parse_maps(input: &str) -> Maps {
+fn parse_maps(input: &str) -> Result<Maps, ParseError> {
     let mut maps = Maps {
         seed_to_soil: vec![],
         soil_to_fertilizer: vec![],
@@ -50,9 +64,9 @@ fn parse_maps(input: &str) -> Maps {
     };
     let input = input.replace("\r\n", "\n");
 
-    input.split("\n\n").skip(1).for_each(|part| {
-        let (map, map_ranges) = part.split_once(" map:\n").unwrap();
-        let vec = match map {
+    for part in Span::new(&input).split("\n\n").skip(1) {
+        let (map, map_ranges) = part.split_once(" map:\n")?;
+        let vec = match map.as_str() {
             "seed-to-soil" => &mut maps.seed_to_soil,
             "soil-to-fertilizer" => &mut maps.soil_to_fertilizer,
             "fertilizer-to-water" => &mut maps.fertilizer_to_water,
@@ -60,27 +74,27 @@ fn parse_maps(input: &str) -> Maps {
             "light-to-temperature" => &mut maps.light_to_temperature,
             "temperature-to-humidity" => &mut maps.temperature_to_humidity,
             "humidity-to-location" => &mut maps.humidity_to_location,
-            _ => unreachable!(),
+            _ => return Err(map.error("unknown map")),
         };
 
-        for line in map_ranges.split('\n') {
-            let mut container = [0; 3];
-            line.split_whitespace()
-                .map(str::parse::<usize>)
-                .filter_map(Result::ok)
-                .enumerate()
-                .for_each(|(i, v)| container[i] = v);
+        for line in map_ranges.lines() {
+            let numbers = line
+                .split_whitespace()
+                .map(|n| n.parse::<usize>("a number"))
+                .collect::<Result<Vec<_>, _>>()?;
 
-            let [destination, source, length] = container;
+            let [destination, source, length] = numbers[..] else {
+                return Err(line.error("expected a destination, a source and a length"));
+            };
             let src_range = source..source + length;
             let dst_range = destination..destination + length;
             let range = (src_range, dst_range);
             vec.push(range);
         }
-    });
+    }
 
     maps.sort();
-    maps
+    Ok(maps)
 }
 
 fn map_seed_to_location(seed: usize, maps: &Maps) -> usize {
@@ -107,7 +121,9 @@ fn merge_ranges(ranges: Vec<Range<usize>>) -> Vec<Range<usize>> {
     ranges.sort_by(|a, b| a.start.cmp(&b.start));
 
     let mut merged = vec![];
-    let mut current = ranges[0].clone();
+    let Some(mut current) = ranges.first().cloned() else {
+        return merged;
+    };
 
     for range in ranges.into_iter().skip(1) {
         if current.end >= range.start {
@@ -123,98 +139,86 @@ fn merge_ranges(ranges: Vec<Range<usize>>) -> Vec<Range<usize>> {
 }
 
 mod p1 {
-    use std::time::Instant;
-
     use crate::etc::Solution;
 
     use super::*;
 
-    fn parse_seeds(input: &str) -> Seeds {
-        let first_row = input.lines().next().unwrap();
+    fn parse_seeds(input: &str) -> Result<Seeds, ParseError> {
+        let first_row = Span::new(input)
+            .lines()
+            .next()
+            .ok_or_else(|| ParseError::new("empty input"))?;
 
-        let (_, seeds) = first_row.split_once(": ").unwrap();
+        let (_, seeds) = first_row.split_once(": ")?;
         let seeds = seeds
-            .split(' ')
-            .map(str::parse::<usize>)
-            .filter_map(Result::ok)
-            .collect::<Vec<_>>();
+            .split_whitespace()
+            .map(|n| n.parse::<usize>("a number"))
+            .collect::<Result<Vec<_>, _>>()?;
 
-        Seeds(seeds)
+        Ok(Seeds(seeds))
     }
 
-    pub(super) fn solve(input: &str) -> Solution {
-        let start = Instant::now();
-
-        let Seeds(seeds) = parse_seeds(input);
-        let maps = parse_maps(input);
+    pub(super) fn solve(input: &str, maps: &Maps) -> Result<Solution, SolveError> {
+        let Seeds(seeds) = parse_seeds(input)?;
         let min = seeds
             .iter()
-            .map(|seed| map_seed_to_location(*seed, &maps))
+            .map(|seed| map_seed_to_location(*seed, maps))
             .min()
-            .unwrap();
-
-        let elapsed_ms = start.elapsed().as_nanos() as f64 / 1_000_000.0;
-        println!("  · Elapsed: {:.4} ms", elapsed_ms);
+            .ok_or_else(|| SolveError::Logic("no seeds to plant".to_string()))?;
 
-        Solution::Usize(min)
+        Ok(Solution::Usize(min))
     }
 }
 
 mod p2 {
-
-    use std::time::Instant;
-
     use super::*;
     use crate::etc::Solution;
 
-    fn parse_seed_ranges(input: &str) -> Vec<Range<usize>> {
-        let first_row = input.lines().next().unwrap();
-
-        let (_, seeds) = first_row.split_once(": ").unwrap();
-        let seeds = seeds
-            .split(' ')
-            .map(str::parse::<usize>)
-            .filter_map(Result::ok)
-            .collect::<Vec<_>>();
+    fn parse_seed_ranges(input: &str) -> Result<Vec<Range<usize>>, ParseError> {
+        let first_row = Span::new(input)
+            .lines()
+            .next()
+            .ok_or_else(|| ParseError::new("empty input"))?;
+
+        let (_, seeds) = first_row.split_once(": ")?;
+        let numbers = seeds
+            .split_whitespace()
+            .map(|n| n.parse::<usize>("a number"))
+            .collect::<Result<Vec<_>, _>>()?;
+        if numbers.len() % 2 != 0 {
+            return Err(seeds.error("expected pairs of a start and a length"));
+        }
 
-        let seed_ranges = seeds
-            .chunks(2)
+        let seed_ranges = numbers
+            .chunks_exact(2)
             .map(|chunk| chunk[0]..(chunk[0] + chunk[1]))
             .collect::<Vec<_>>();
 
-        merge_ranges(seed_ranges)
+        Ok(merge_ranges(seed_ranges))
     }
 
-    pub(super) fn solve(input: &str) -> Solution {
-        let start = Instant::now();
-        let seed_ranges = parse_seed_ranges(input);
-        let maps = parse_maps(input);
+    pub(super) fn solve(input: &str, maps: &Maps) -> Result<Solution, SolveError> {
+        let seed_ranges = parse_seed_ranges(input)?;
 
         let answer = seed_ranges
             .into_iter()
-            .map(|r| {
-                (r.start..r.end)
-                    .map(|seed| map_seed_to_location(seed, &maps))
-                    .min()
-                    .unwrap()
-            })
+            .flatten()
+            .map(|seed| map_seed_to_location(seed, maps))
             .min()
-            .unwrap();
+            .ok_or_else(|| SolveError::Logic("no seeds to plant".to_string()))?;
 
-        let elapsed_ms = start.elapsed().as_nanos() as f64 / 1_000_000.0;
-        println!("  · Elapsed: {:.4} ms", elapsed_ms);
-        Solution::Usize(answer)
+        Ok(Solution::Usize(answer))
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::etc::Solution;
+    use crate::etc::{Solution, Timer};
 
     #[test]
     fn test_sample_input() {
-        let input = include_str!("../../input/day05/test.txt");
-        let (p1, p2) = super::solve(input);
+        let input = include_str!("../../../input/2023/day05/test.txt");
+        let (p1, p2) = super::solve(input, &mut Timer::default()).unwrap();
         assert_eq!(p1, Solution::Usize(35));
         assert_eq!(p2, Solution::Usize(46));
     }
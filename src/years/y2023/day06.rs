@@ -0,0 +1,85 @@
+use crate::{
+    utils::parse::{ParseError, Span},
+    years::Day,
+    Solution, SolutionPair, SolveError, Timer,
+};
+
+pub const DAY: Day = Day {
+    number: 6,
+    title: "Wait For It",
+    implemented: [true, true],
+    samples: [Some("288"), Some("71503")],
+    solve,
+};
+
+pub fn solve(input: &str, timer: &mut Timer) -> Result<SolutionPair, SolveError> {
+    Ok((timer.part1(|| p1(input))?, timer.part2(|| p2(input))?))
+}
+
+fn p1(input: &str) -> Result<Solution, SolveError> {
+    fn parse_line(line: Span) -> Result<Vec<usize>, ParseError> {
+        line.split_whitespace()
+            .skip(1)
+            .map(|n| n.parse::<usize>("a number"))
+            .collect()
+    }
+    let (l1, l2) = Span::new(input).split_once("\n")?;
+    let times = parse_line(l1)?;
+    let records = parse_line(l2)?;
+
+    Ok(times
+        .into_iter()
+        .zip(records)
+        .map(|(t, d)| f(t, d))
+        .product::<usize>()
+        .into())
+}
+
+fn p2(input: &str) -> Result<Solution, SolveError> {
+    fn parse_line(line: Span) -> Result<usize, ParseError> {
+        line.split_whitespace()
+            .skip(1)
+            .map(|n| n.as_str())
+            .collect::<String>()
+            .parse::<usize>()
+            .map_err(|_| line.error("expected a number"))
+    }
+    let (l1, l2) = Span::new(input).split_once("\n")?;
+    let times = parse_line(l1)?;
+    let records = parse_line(l2)?;
+
+    Ok(f(times, records).into())
+}
+
+fn f(t: usize, d: usize) -> usize {
+    let (t, d) = (t as f64, d as f64);
+    let center = t / 2.0;
+    let diff = ((t / 2.0).powi(2) - d).sqrt();
+
+    let root1 = match center - diff {
+        x if x.fract() == 0.0 => x + 1.0,
+        x => x.ceil(),
+    } as usize;
+
+    let root2 = match center + diff {
+        x if x.fract() == 0.0 => x - 1.0,
+        x => x.floor(),
+    } as usize;
+    
+    let c1 = root1;
+    let c2 = root2;
+    (c1..=c2).count()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::etc::{Solution, Timer};
+
+    #[test]
+    fn test_sample_input() {
+        let input = include_str!("../../../input/2023/day06/test.txt");
+        let (p1, p2) = super::solve(input, &mut Timer::default()).unwrap();
+        assert_eq!(p1, Solution::Usize(288));
+        assert_eq!(p2, Solution::Usize(71503));
+    }
+}
@@ -1,4 +1,8 @@
-use crate::{Solution, SolutionPair};
+use crate::{
+    utils::parse::{ParseError, Span},
+    years::Day,
+    Solution, SolutionPair, SolveError, Timer,
+};
 
 #[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Clone, Copy, Hash)]
 pub struct Bid(pub usize);
@@ -19,10 +23,14 @@ const J: usize = 11;
 const T: usize = 10;
 
 impl Hand {
-    fn parse(s: &str) -> Self {
+    fn parse(s: Span) -> Result<Self, ParseError> {
+        if s.len() != 5 {
+            return Err(s.error("expected 5 cards"));
+        }
+
         let mut cards = [0; 5];
         let mut i = 0;
-        for card in s.as_bytes() {
+        for card in s.as_str().as_bytes() {
             cards[i] = match card {
                 b'A' => A,
                 b'K' => K,
@@ -30,12 +38,12 @@ impl Hand {
                 b'J' => J,
                 b'T' => T,
                 b'2'..=b'9' => (card - b'0') as usize,
-                _ => unreachable!(),
+                _ => return Err(s.skip(i).error("expected a card")),
             };
             i += 1;
         }
 
-        Self(cards)
+        Ok(Self(cards))
     }
 
     fn rank(self, part: Part) -> usize {
@@ -83,18 +91,28 @@ impl Hand {
 #[derive(Debug, Clone, Copy)]
 struct Line(Hand, Bid);
 
-pub fn solve(input: &str) -> SolutionPair {
-    let lines = input
-        .lines()
-        .map(|line| {
-            let (hand, bid) = line.split_once(' ').unwrap();
-            let hand = Hand::parse(hand);
-            let bid = Bid(bid.parse().unwrap());
-            Line(hand, bid)
-        })
-        .collect::<Vec<_>>();
-
-    (p1(&lines), p2(&lines))
+pub const DAY: Day = Day {
+    number: 7,
+    title: "Camel Cards",
+    implemented: [true, true],
+    samples: [Some("6440"), Some("5905")],
+    solve,
+};
+
+pub fn solve(input: &str, timer: &mut Timer) -> Result<SolutionPair, SolveError> {
+    let lines = timer.parse(|| {
+        Span::new(input)
+            .lines()
+            .map(|line| {
+                let (hand, bid) = line.split_once(" ")?;
+                let hand = Hand::parse(hand)?;
+                let bid = Bid(bid.parse("a bid")?);
+                Ok(Line(hand, bid))
+            })
+            .collect::<Result<Vec<_>, ParseError>>()
+    })?;
+
+    Ok((timer.part1(|| p1(&lines))?, timer.part2(|| p2(&lines))?))
 }
 
 fn p1(lines: &[Line]) -> Solution {
@@ -126,12 +144,12 @@ fn sort(lines: &[Line], part: Part) -> usize {
 
 #[cfg(test)]
 mod tests {
-    use crate::etc::Solution;
+    use crate::etc::{Solution, Timer};
 
     #[test]
     fn test_sample_input() {
-        let input = include_str!("../../input/day07/test.txt");
-        let (p1, p2) = super::solve(input);
+        let input = include_str!("../../../input/2023/day07/test.txt");
+        let (p1, p2) = super::solve(input, &mut Timer::default()).unwrap();
         assert_eq!(p1, Solution::Usize(6440));
         assert_eq!(p2, Solution::Usize(5905));
     }
@@ -0,0 +1,143 @@
+use std::collections::HashMap;
+
+use crate::{
+    utils::{
+        bigint::BigInt,
+        parse::{ParseError, Span},
+    },
+    years::Day,
+    Solution, SolutionPair, SolveError, Timer,
+};
+
+pub(super) struct Input<'a> {
+    instructions: Vec<u8>,
+    network_map: HashMap<&'a str, (&'a str, &'a str)>,
+}
+
+pub const DAY: Day = Day {
+    number: 8,
+    title: "Haunted Wasteland",
+    implemented: [true, true],
+    samples: [Some("6"), Some("6")],
+    solve,
+};
+
+pub fn solve(input: &str, timer: &mut Timer) -> Result<SolutionPair, SolveError> {
+    let input = timer.parse(|| parse_input(input))?;
+
+    Ok((timer.part1(|| p1(&input))?, timer.part2(|| p2(&input))?))
+}
+
+pub(super) fn p1(input: &Input) -> Result<Solution, SolveError> {
+    let mut current = "AAA";
+    let mut steps = 0;
+    let iterator = input.instructions.iter().cycle();
+    for dir in iterator {
+        current = move_to(&input.network_map, current, *dir)?;
+        steps += 1;
+        if current == "ZZZ" {
+            break;
+        }
+    }
+    Ok(Solution::Usize(steps))
+}
+
+pub(super) fn p2(input: &Input) -> Result<Solution, SolveError> {
+    let mut locations = input
+        .network_map
+        .keys()
+        .filter(|k| k.ends_with('A'))
+        .map(|k| (*k, 1usize))
+        .collect::<Vec<_>>();
+
+    let mut path_lengths = Vec::new();
+    for dir in input.instructions.iter().cycle() {
+        if locations.is_empty() {
+            break;
+        }
+
+        let mut next = Vec::with_capacity(locations.len());
+        for (location, steps) in locations {
+            let new = move_to(&input.network_map, location, *dir)?;
+            if new.ends_with('Z') {
+                path_lengths.push(steps)
+            } else {
+                next.push((new, steps + 1));
+            }
+        }
+        locations = next;
+    }
+
+    Ok(lcm(&path_lengths))
+}
+
+/// The least common multiple, which can outgrow the native integers.
+fn lcm(numbers: &[usize]) -> Solution {
+    let lcm = numbers.iter().fold(BigInt::from(1), |lcm, &n| {
+        let n = BigInt::from(n);
+        let gcd = lcm.gcd(&n);
+        lcm / gcd * n
+    });
+
+    Solution::from(lcm)
+}
+
+/// Follows one instruction, which the parser has checked to be `L` or `R`.
+fn move_to<'a>(
+    map: &HashMap<&'a str, (&'a str, &'a str)>,
+    current: &'a str,
+    dir: u8,
+) -> Result<&'a str, SolveError> {
+    let (left, right) = map
+        .get(current)
+        .ok_or_else(|| SolveError::Logic(format!("no node '{}' in the network", current)))?;
+    Ok(if dir == b'L' { left } else { right })
+}
+
+pub(super) fn parse_input(input: &str) -> Result<Input, ParseError> {
+    let (instructions, map) = Span::new(input).split_once("\n\n")?;
+
+    if instructions.is_empty() {
+        return Err(instructions.error("expected instructions"));
+    }
+    let instructions = instructions
+        .as_str()
+        .bytes()
+        .enumerate()
+        .map(|(i, c)| match c {
+            b'L' | b'R' => Ok(c),
+            _ => Err(instructions.skip(i).error("expected 'L' or 'R'")),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let network_map = map
+        .lines()
+        .map(|line| {
+            let (from, to) = line.split_once(" = (")?;
+            let (left, right) = to.split_once(", ")?;
+            let right = right.strip_suffix(")")?;
+            Ok((from.as_str(), (left.as_str(), right.as_str())))
+        })
+        .collect::<Result<HashMap<_, _>, ParseError>>()?;
+
+    Ok(Input {
+        instructions,
+        network_map,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::etc::Solution;
+
+    #[test]
+    fn test_sample_input() {
+        let input_p1 = include_str!("../../../input/2023/day08/test_p1.txt");
+        let input_p2 = include_str!("../../../input/2023/day08/test_p2.txt");
+        let input_p1 = super::parse_input(input_p1).unwrap();
+        let input_p2 = super::parse_input(input_p2).unwrap();
+        let (p1, p2) = (super::p1(&input_p1).unwrap(), super::p2(&input_p2).unwrap());
+        assert_eq!(p1, Solution::Usize(6));
+        assert_eq!(p2, Solution::Usize(6));
+    }
+}
@@ -0,0 +1,89 @@
+use crate::{
+    utils::parse::{ParseError, Span},
+    years::Day,
+    Solution, SolutionPair, SolveError, Timer,
+};
+
+pub const DAY: Day = Day {
+    number: 9,
+    title: "Mirage Maintenance",
+    implemented: [true, true],
+    samples: [Some("114"), Some("2")],
+    solve,
+};
+
+pub fn solve(input: &str, timer: &mut Timer) -> Result<SolutionPair, SolveError> {
+    let sequences = timer.parse(|| {
+        Span::new(input).lines().map(parse_line).collect::<Result<Vec<_>, _>>()
+    })?;
+
+    Ok((
+        timer.part1(|| p1(&sequences))?,
+        timer.part2(|| p2(&sequences))?,
+    ))
+}
+
+fn p1(sequences: &[Vec<isize>]) -> Solution {
+    let answer = sequences.iter().map(|s| compute_next_in_sequence(s)).sum();
+
+    Solution::Isize(answer)
+}
+
+fn p2(sequences: &[Vec<isize>]) -> Solution {
+    let answer = sequences.iter().map(|s| extrapolate_backwards(s)).sum();
+
+    Solution::Isize(answer)
+}
+
+fn parse_line(line: Span) -> Result<Vec<isize>, ParseError> {
+    let sequence = line
+        .split_whitespace()
+        .map(|n| n.parse::<isize>("a number"))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if sequence.is_empty() {
+        return Err(line.error("expected a sequence of numbers"));
+    }
+    Ok(sequence)
+}
+
+fn compute_next_in_sequence(sequence: &[isize]) -> isize {
+    let Some(&last_current) = sequence.last() else {
+        return 0;
+    };
+    if all_same(sequence) {
+        last_current
+    } else {
+        let diffs = sequence.windows(2).map(|window| window[1] - window[0]).collect::<Vec<_>>();
+        last_current + compute_next_in_sequence(&diffs)
+    }
+}
+
+fn extrapolate_backwards(sequence: &[isize]) -> isize {
+    let Some(&first_current) = sequence.first() else {
+        return 0;
+    };
+    if all_same(sequence) {
+        first_current
+    } else {
+        let diffs = sequence.windows(2).map(|window| window[1] - window[0]).collect::<Vec<_>>();
+        first_current - extrapolate_backwards(&diffs)
+    }
+}
+
+fn all_same(diffs: &[isize]) -> bool {
+    diffs.windows(2).all(|window| window[0] == window[1])
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::etc::{Solution, Timer};
+
+    #[test]
+    fn test_sample_input() {
+        let input = include_str!("../../../input/2023/day09/test.txt");
+        let (p1, p2) = super::solve(input, &mut Timer::default()).unwrap();
+        assert_eq!(p1, Solution::Isize(114));
+        assert_eq!(p2, Solution::Isize(2));
+    }
+}
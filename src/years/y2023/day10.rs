@@ -0,0 +1,243 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use crate::{
+    utils::{
+        parse::{ParseError, Span},
+        vector_2d::*,
+    },
+    years::Day,
+    Solution, SolutionPair, SolveError, Timer,
+};
+
+#[derive(Debug, Clone)]
+pub(crate) struct Map {
+    tiles: HashMap<Vector2, u8>,
+}
+impl Map {
+    fn get(&self, position: Vector2) -> Option<&u8> {
+        self.tiles.get(&position)
+    }
+}
+
+pub const DAY: Day = Day {
+    number: 10,
+    title: "Pipe Maze",
+    implemented: [true, true],
+    samples: [Some("4"), Some("1")],
+    solve,
+};
+
+pub fn solve(input: &str, timer: &mut Timer) -> Result<SolutionPair, SolveError> {
+    let (map, start) = timer.parse(|| -> Result<_, SolveError> {
+        let mut map = parse_map(input)?;
+
+        let start = map
+            .tiles
+            .iter()
+            .find(|(_, c)| **c == b'S')
+            .map(|(pos, _)| *pos)
+            .ok_or_else(|| ParseError::new("no start tile"))?;
+
+        // Replace the start with the pipe it stands on, so it can be followed like any other
+        let symbol = get_start_symbol(&map, start)?;
+        map.tiles.insert(start, symbol);
+
+        Ok((map, start))
+    })?;
+
+    Ok((
+        timer.part1(|| p1(&map, start))?,
+        timer.part2(|| p2(map.clone(), start))?,
+    ))
+}
+
+fn parse_map(input: &str) -> Result<Map, ParseError> {
+    let mut tiles = HashMap::new();
+    for (y, line) in Span::new(input).lines().rev().enumerate() {
+        for (x, c) in line.as_str().bytes().enumerate() {
+            if !b"|-LF7J.S".contains(&c) {
+                return Err(line.skip(x).error(format!("invalid tile '{}'", c as char)));
+            }
+            tiles.insert(Vector2::new_usize(x, y), c);
+        }
+    }
+    Ok(Map { tiles })
+}
+
+fn p1(map: &Map, start: Vector2) -> Solution {
+    let pipe = find_pipe(map, start);
+
+    Solution::from(pipe.len().div_ceil(2))
+}
+
+fn find_pipe(map: &Map, start: Vector2) -> Vec<Vector2> {
+    let mut todo = vec![start];
+
+    let mut pipe = Vec::new();
+    let mut visits = HashSet::new();
+
+    while let Some(pos) = todo.pop() {
+        let c = match map.tiles.get(&pos) {
+            Some(&c) => c,
+            None => continue,
+        };
+
+        if !visits.insert(pos) {
+            continue;
+        }
+        pipe.push(pos);
+
+        get_exits(c)
+            .into_iter()
+            .flatten()
+            .map(|d| pos + d)
+            .filter(|x| !visits.contains(x))
+            .for_each(|x| todo.push(x));
+    }
+
+    pipe
+}
+
+fn get_start_symbol(map: &Map, start: Vector2) -> Result<u8, SolveError> {
+    let results = [N, E, W, S]
+        .into_iter()
+        .map(|dir| {
+            let pos = start + dir;
+            map.get(pos)
+                .and_then(|c| get_exits(*c))
+                .is_some_and(|exits| exits.contains(&dir.inverse()))
+        })
+        .collect::<Vec<_>>();
+
+    match results.as_slice() {
+        [true, true, false, false] => Ok(b'L'), // North and East
+        [false, true, false, true] => Ok(b'F'), // East and South
+        [false, false, true, true] => Ok(b'7'), // South and West
+        [true, false, true, false] => Ok(b'J'), // North and West
+        [true, false, false, true] => Ok(b'|'), // North and South
+        [false, true, true, false] => Ok(b'-'), // East and West
+        _ => Err(SolveError::Logic(
+            "the start tile does not connect to exactly two pipes".to_string(),
+        )),
+    }
+}
+
+/// The directions a pipe connects, or `None` for ground.
+fn get_exits(shape: u8) -> Option<[Vector2; 2]> {
+    match shape {
+        b'|' => Some([N, S]),
+        b'-' => Some([W, E]),
+        b'L' => Some([N, E]),
+        b'F' => Some([S, E]),
+        b'7' => Some([W, S]),
+        b'J' => Some([W, N]),
+        _ => None,
+    }
+}
+
+fn p2(mut map: Map, start: Vector2) -> Result<Solution, SolveError> {
+    let pipe = find_pipe(&map, start);
+    map.tiles.iter_mut().for_each(|(pos, c)| {
+        if !pipe.contains(pos) {
+            *c = b'.';
+        }
+    });
+
+    // The search starts from the start tile, so the pipe does too
+    let mut current = start;
+    let mut inside = HashSet::new();
+    for next in pipe.into_iter().skip(1) {
+        let direction = next - current;
+        let tiles = get_inside_tiles(&map, current, direction)?
+            .iter()
+            .map(|&d| current + d)
+            .filter_map(|p| map.tiles.get(&p).map(|&c| (p, c)))
+            .filter(|(p, c)| c == &b'.' && !inside.contains(p))
+            .collect::<Vec<_>>();
+
+        for tile in tiles {
+            floodfill(&map, &mut inside, tile.0);
+        }
+        current = next;
+    }
+
+    for inside in inside.iter() {
+        if let Some(tile) = map.tiles.get_mut(inside) {
+            *tile = b'0';
+        }
+    }
+
+    Ok(Solution::from(inside.len()))
+}
+
+fn floodfill(map: &Map, inside: &mut HashSet<Vector2>, start: Vector2) {
+    let mut todo = VecDeque::new();
+    todo.push_back(start);
+
+    while let Some(pos) = todo.pop_front() {
+        if let Some(&c) = map.tiles.get(&pos) {
+            if c == b'.' && inside.insert(pos) {
+                pos.adjacent_points()
+                    .iter()
+                    .for_each(|&p| todo.push_back(p));
+            }
+        }
+    }
+}
+
+fn get_inside_tiles(
+    map: &Map,
+    position: Vector2,
+    direction: Vector2,
+) -> Result<Vec<Vector2>, SolveError> {
+    let tiles = match (map.get(position).copied(), direction) {
+        (Some(b'|'), N) => vec![E],
+        (Some(b'|'), S) => vec![W],
+        (Some(b'-'), W) => vec![N],
+        (Some(b'-'), E) => vec![S],
+        (Some(b'L'), N) => vec![NE],
+        (Some(b'L'), E) => vec![W, SW, S],
+        (Some(b'F'), E) => vec![SE],
+        (Some(b'F'), S) => vec![W, NW, N],
+        (Some(b'7'), S) => vec![SW],
+        (Some(b'7'), W) => vec![E, NE, N],
+        (Some(b'J'), W) => vec![NW],
+        (Some(b'J'), N) => vec![E, SE, S],
+        _ => {
+            return Err(SolveError::Logic(format!(
+                "the pipe does not form a single loop at {}",
+                position
+            )))
+        }
+    };
+    Ok(tiles)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::etc::{Solution, SolveError, Timer};
+
+    #[test]
+    fn test_invalid_input() {
+        let mut timer = Timer::default();
+        let no_start = super::solve("..\n.F", &mut timer);
+        let bad_tile = super::solve(".S\n.X", &mut timer);
+        let dead_end = super::solve("S-\n..", &mut timer);
+
+        assert!(matches!(no_start, Err(SolveError::Parse(_))));
+        let Err(SolveError::Parse(bad_tile)) = bad_tile else {
+            panic!("expected a parse error");
+        };
+        assert_eq!(bad_tile.message, "invalid tile 'X'");
+        assert_eq!(bad_tile.location.map(|l| (l.line, l.column)), Some((2, 2)));
+        assert!(matches!(dead_end, Err(SolveError::Logic(_))));
+    }
+
+    #[test]
+    fn test_sample_input() {
+        let input = include_str!("../../../input/2023/day10/test.txt");
+        let (p1, p2) = super::solve(input, &mut Timer::default()).unwrap();
+        assert_eq!(p1, Solution::Usize(4));
+        assert_eq!(p2, Solution::Usize(1));
+    }
+}
@@ -1,10 +1,20 @@
-use std::collections::HashMap;
+use crate::{
+    utils::{grid::Grid, parse::ParseError, vector_2d::Vector2},
+    years::Day,
+    Solution, SolutionPair, SolveError, Timer,
+};
 
-use crate::{utils::vector_2d::Vector2, Solution, SolutionPair};
+pub const DAY: Day = Day {
+    number: 11,
+    title: "Cosmic Expansion",
+    implemented: [true, true],
+    samples: [Some("374"), Some("82000210")],
+    solve,
+};
 
-pub fn solve(input: &str) -> SolutionPair {
-    let map = Map::parse(input);
-    (p1(&map), p2(&map))
+pub fn solve(input: &str, timer: &mut Timer) -> Result<SolutionPair, SolveError> {
+    let map = timer.parse(|| Map::parse(input))?;
+    Ok((timer.part1(|| p1(&map))?, timer.part2(|| p2(&map))?))
 }
 
 fn p1(map: &Map) -> Solution {
@@ -43,25 +53,15 @@ struct Map {
     galaxies: Vec<Vector2>,
 }
 impl Map {
-    fn parse(input: &str) -> Self {
-        let mut map = HashMap::new();
-        let mut galaxies = Vec::new();
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let grid = Grid::parse(input, |c| matches!(c, '.' | '#').then_some(c))?;
+        let galaxies = grid
+            .iter()
+            .filter(|(_, &c)| c == '#')
+            .map(|(pos, _)| pos)
+            .collect();
 
-        for (y, line) in input.lines().rev().enumerate() {
-            for (x, c) in line.chars().enumerate() {
-                let pos = Vector2::new_usize(x, y);
-                let val = match c {
-                    '.' => b'.',
-                    '#' => {
-                        galaxies.push(pos);
-                        b'#'
-                    }
-                    _ => panic!("Invalid input"),
-                };
-                map.insert(pos, val);
-            }
-        }
-        Map { galaxies }
+        Ok(Map { galaxies })
     }
 
     fn extra_distance_between(&self, a: Vector2, b: Vector2, size: usize) -> usize {
@@ -92,12 +92,12 @@ impl Map {
 
 #[cfg(test)]
 mod tests {
-    use crate::etc::Solution;
+    use crate::etc::{Solution, Timer};
 
     #[test]
     fn test_sample_input() {
-        let input = include_str!("../../input/day11/test.txt");
-        let (p1, p2) = super::solve(input);
+        let input = include_str!("../../../input/2023/day11/test.txt");
+        let (p1, p2) = super::solve(input, &mut Timer::default()).unwrap();
         assert_eq!(p1, Solution::Usize(374));
         assert_eq!(p2, Solution::Usize(82000210));
     }
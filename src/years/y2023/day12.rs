@@ -1,6 +1,10 @@
 use std::collections::HashMap;
 
-use crate::{Solution, SolutionPair};
+use crate::{
+    utils::parse::{ParseError, Span},
+    years::Day,
+    Solution, SolutionPair, SolveError, Timer,
+};
 
 type Memo = HashMap<(Vec<u8>, Vec<usize>), usize>;
 
@@ -10,28 +14,25 @@ struct Input {
 }
 
 impl Input {
-    fn parse(input: &str, repeats: usize) -> Self {
-        let lines = input
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let lines = Span::new(input)
             .lines()
             .map(|line| {
-                let (springs, pattern) = line.split_once(' ').unwrap();
-                let springs = std::iter::repeat(springs)
-                    .take(repeats)
-                    .collect::<Vec<_>>()
-                    .join("?")
-                    .bytes()
-                    .collect::<Vec<_>>();
+                let (springs, pattern) = line.split_once(" ")?;
+                if let Some(i) = springs.as_str().find(|c| !matches!(c, '#' | '.' | '?')) {
+                    return Err(springs.skip(i).error("expected '#', '.' or '?'"));
+                }
+                let springs = springs.as_str().bytes().collect::<Vec<_>>();
 
                 let pattern = pattern
-                    .split(',')
-                    .filter_map(|s| s.parse::<usize>().ok())
-                    .collect::<Vec<_>>()
-                    .repeat(repeats);
+                    .split(",")
+                    .map(|s| s.parse::<usize>("a number"))
+                    .collect::<Result<Vec<_>, _>>()?;
 
-                Line { springs, pattern }
+                Ok(Line { springs, pattern })
             })
-            .collect();
-        Self { lines }
+            .collect::<Result<_, _>>()?;
+        Ok(Self { lines })
     }
 }
 
@@ -41,6 +42,15 @@ struct Line {
     pattern: Vec<usize>,
 }
 
+impl Line {
+    fn unfold(&self, repeats: usize) -> Self {
+        let springs = vec![self.springs.as_slice(); repeats].join(&b'?');
+        let pattern = self.pattern.repeat(repeats);
+
+        Line { springs, pattern }
+    }
+}
+
 struct Rules<'a> {
     pattern: &'a [u8],
     solution: &'a [usize],
@@ -90,16 +100,25 @@ impl<'a> Rules<'a> {
     }
 }
 
-pub fn solve(input: &str) -> SolutionPair {
-    (p1(input), p2(input))
+pub const DAY: Day = Day {
+    number: 12,
+    title: "Hot Springs",
+    implemented: [true, true],
+    samples: [Some("21"), Some("525152")],
+    solve,
+};
+
+pub fn solve(input: &str, timer: &mut Timer) -> Result<SolutionPair, SolveError> {
+    let input = timer.parse(|| Input::parse(input))?;
+    Ok((timer.part1(|| p1(&input))?, timer.part2(|| p2(&input))?))
 }
 
-fn p1(input: &str) -> Solution {
-    let mut input = Input::parse(input, 1);
+fn p1(input: &Input) -> Solution {
     let total_arrangements = input
         .lines
-        .iter_mut()
+        .iter()
         .map(|l| {
+            let mut l = l.clone();
             let mut memo = Memo::new();
             count_arrangements(&mut l.springs, &mut l.pattern, &mut memo)
         })
@@ -108,13 +127,12 @@ fn p1(input: &str) -> Solution {
     Solution::Usize(total_arrangements)
 }
 
-fn p2(input: &str) -> Solution {
-    let mut input = Input::parse(input, 5);
-
+fn p2(input: &Input) -> Solution {
     let total_arrangements = input
         .lines
-        .iter_mut()
+        .iter()
         .map(|l| {
+            let mut l = l.unfold(5);
             let mut memo = Memo::new();
             count_arrangements(&mut l.springs, &mut l.pattern, &mut memo)
         })
@@ -169,7 +187,8 @@ fn count_arrangements(pattern: &mut [u8], solution: &mut [usize], memo: &mut Mem
         Some(&b'#') => pound(pattern, solution, memo),
         Some(&b'.') => dot(pattern, solution, memo),
         Some(&b'?') => pound(pattern, solution, memo) + dot(pattern, solution, memo),
-        _ => unreachable!("Invalid input"),
+        // The parser only accepts '#', '.' and '?', and an empty pattern is handled above
+        _ => 0,
     };
     memo.insert(key, res);
     res
@@ -181,7 +200,7 @@ mod tests {
 
     use itertools::Itertools;
 
-    use crate::etc::Solution;
+    use crate::etc::{Solution, Timer};
     type Memo = HashMap<(Vec<u8>, Vec<usize>), usize>;
 
     #[test]
@@ -302,8 +321,8 @@ mod tests {
 
     #[test]
     fn test_sample_input() {
-        let input = include_str!("../../input/day12/test.txt");
-        let (p1, p2) = super::solve(input);
+        let input = include_str!("../../../input/2023/day12/test.txt");
+        let (p1, p2) = super::solve(input, &mut Timer::default()).unwrap();
         assert_eq!(p1, Solution::Usize(21));
         assert_eq!(p2, Solution::Usize(525152));
     }
@@ -1,4 +1,8 @@
-use crate::{Solution, SolutionPair};
+use crate::{
+    utils::parse::{ParseError, Span},
+    years::Day,
+    Solution, SolutionPair, SolveError, Timer,
+};
 
 struct Valley(Vec<Vec<u8>>);
 
@@ -72,25 +76,43 @@ struct PuzzleInput {
 }
 
 impl PuzzleInput {
-    fn parse(input: &str) -> Self {
-        let valleys = input
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let valleys = Span::new(input)
+            .trim()
             .split("\n\n")
             .map(|field| {
-                let valley = field
-                    .lines()
-                    .map(|line| line.as_bytes().to_vec())
+                // The reflections are searched in a rectangle of at least one tile
+                let lines = field.lines().collect::<Vec<_>>();
+                let Some(width) = lines.first().map(Span::len).filter(|&width| width > 0) else {
+                    return Err(field.error("expected a pattern"));
+                };
+                if let Some(line) = lines.iter().find(|line| line.len() != width) {
+                    return Err(line.error(format!("expected {} tiles", width)));
+                }
+
+                let valley = lines
+                    .iter()
+                    .map(|line| line.as_str().as_bytes().to_vec())
                     .collect::<Vec<_>>();
-                Valley(valley)
+                Ok(Valley(valley))
             })
-            .collect::<Vec<_>>();
+            .collect::<Result<Vec<_>, _>>()?;
 
-        PuzzleInput { valleys }
+        Ok(PuzzleInput { valleys })
     }
 }
 
-pub fn solve(input: &str) -> SolutionPair {
-    let input = PuzzleInput::parse(input);
-    (p1(&input), p2(input))
+pub const DAY: Day = Day {
+    number: 13,
+    title: "Point of Incidence",
+    implemented: [true, true],
+    samples: [Some("405"), Some("400")],
+    solve,
+};
+
+pub fn solve(input: &str, timer: &mut Timer) -> Result<SolutionPair, SolveError> {
+    let input = timer.parse(|| PuzzleInput::parse(input))?;
+    Ok((timer.part1(|| p1(&input))?, timer.part2(|| p2(&input))?))
 }
 
 fn p1(input: &PuzzleInput) -> Solution {
@@ -102,7 +124,7 @@ fn p1(input: &PuzzleInput) -> Solution {
     Solution::Usize(columns + rows * 100)
 }
 
-fn p2(input: PuzzleInput) -> Solution {
+fn p2(input: &PuzzleInput) -> Solution {
     let (columns, rows) = input
         .valleys
         .iter()
@@ -113,12 +135,12 @@ fn p2(input: PuzzleInput) -> Solution {
 
 #[cfg(test)]
 mod tests {
-    use crate::etc::Solution;
+    use crate::etc::{Solution, Timer};
 
     #[test]
     fn test_sample_input() {
-        let input = include_str!("../../input/day13/test.txt");
-        let (p1, p2) = super::solve(input);
+        let input = include_str!("../../../input/2023/day13/test.txt");
+        let (p1, p2) = super::solve(input, &mut Timer::default()).unwrap();
         assert_eq!(p1, Solution::Usize(405));
         assert_eq!(p2, Solution::Usize(400));
     }
@@ -1,6 +1,10 @@
 use std::collections::HashMap;
 
-use crate::{Solution, SolutionPair};
+use crate::{
+    utils::{grid::Grid, parse::ParseError},
+    years::Day,
+    Solution, SolutionPair, SolveError, Timer,
+};
 
 #[derive(Clone, Debug, Hash, Eq, PartialEq)]
 struct Map {
@@ -8,14 +12,20 @@ struct Map {
 }
 
 impl Map {
-    fn parse(input: &str) -> Self {
-        let tiles = input
-            .lines()
-            .rev()
-            .map(|line| line.bytes().collect())
-            .collect();
-
-        Self { tiles }
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let grid = Grid::parse(input, |c| matches!(c, 'O' | '#' | '.').then_some(c as u8))?;
+
+        // Tilting in every direction rotates the platform in place
+        if grid.width() == 0 || grid.width() != grid.height() {
+            return Err(ParseError::new(format!(
+                "expected a square platform, found {}x{} tiles",
+                grid.width(),
+                grid.height()
+            )));
+        }
+        let tiles = grid.rows().map(<[u8]>::to_vec).collect();
+
+        Ok(Self { tiles })
     }
 
     fn tilt_up(&mut self) {
@@ -32,13 +42,13 @@ impl Map {
                             self.tiles[row + spaces][col] = b'O';
                         }
                     }
-                    b'#' => {
-                        spaces = 0;
-                    }
                     b'.' => {
                         spaces += 1;
                     }
-                    _ => panic!("Invalid character in map"),
+                    // `#`, the only other tile the parser accepts
+                    _ => {
+                        spaces = 0;
+                    }
                 }
             }
         }
@@ -74,9 +84,17 @@ impl Map {
     }
 }
 
-pub fn solve(input: &str) -> SolutionPair {
-    let map = Map::parse(input);
-    (p1(map.clone()), p2(map))
+pub const DAY: Day = Day {
+    number: 14,
+    title: "Parabolic Reflector Dish",
+    implemented: [true, true],
+    samples: [Some("136"), Some("64")],
+    solve,
+};
+
+pub fn solve(input: &str, timer: &mut Timer) -> Result<SolutionPair, SolveError> {
+    let map = timer.parse(|| Map::parse(input))?;
+    Ok((timer.part1(|| p1(map.clone()))?, timer.part2(|| p2(map))?))
 }
 
 fn p1(mut map: Map) -> Solution {
@@ -85,7 +103,7 @@ fn p1(mut map: Map) -> Solution {
     Solution::Usize(map.count())
 }
 
-fn p2(mut map: Map) -> Solution {
+fn p2(mut map: Map) -> Result<Solution, SolveError> {
     let cycles = 1_000_000_000;
     let mut cache = HashMap::new();
     for cycle in 1..cycles {
@@ -94,21 +112,23 @@ fn p2(mut map: Map) -> Solution {
             let remaining = cycles - cycle;
             let cycle_length = cycle - last_seen;
             if remaining % cycle_length == 0 {
-                return Solution::Usize(result);
+                return Ok(Solution::Usize(result));
             }
         }
     }
-    unreachable!("No solution found")
+    Err(SolveError::Logic(
+        "the platform does not settle into a cycle".to_string(),
+    ))
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::etc::Solution;
+    use crate::etc::{Solution, Timer};
 
     #[test]
     fn test_sample_input() {
-        let input = include_str!("../../input/day14/test.txt");
-        let (p1, p2) = super::solve(input);
+        let input = include_str!("../../../input/2023/day14/test.txt");
+        let (p1, p2) = super::solve(input, &mut Timer::default()).unwrap();
         assert_eq!(p1, Solution::Usize(136));
         assert_eq!(p2, Solution::Usize(64));
     }
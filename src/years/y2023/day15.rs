@@ -1,4 +1,8 @@
-use crate::{Solution, SolutionPair};
+use crate::{
+    utils::parse::{ParseError, Span},
+    years::Day,
+    Solution, SolutionPair, SolveError, Timer,
+};
 
 #[derive(Debug, Hash, PartialEq, Eq, Clone, Copy)]
 enum Instruction {
@@ -13,19 +17,19 @@ struct Label {
 }
 
 impl Label {
-    fn parse(s: &str) -> Self {
-        match s.strip_suffix('-') {
-            Some(label) => Self {
+    fn parse(s: Span) -> Result<Self, ParseError> {
+        match s.strip_suffix("-") {
+            Ok(label) => Ok(Self {
                 instruction: Instruction::Minus,
-                label: label.as_bytes().to_vec(),
-            },
-            None => {
-                let (label, focal_length) = s.split_once('=').unwrap();
-                let focal_length = focal_length.parse().unwrap();
-                Self {
+                label: label.as_str().as_bytes().to_vec(),
+            }),
+            Err(_) => {
+                let (label, focal_length) = s.split_once("=")?;
+                let focal_length = focal_length.parse("a focal length")?;
+                Ok(Self {
                     instruction: Instruction::Equal(focal_length),
-                    label: label.as_bytes().to_vec(),
-                }
+                    label: label.as_str().as_bytes().to_vec(),
+                })
             }
         }
     }
@@ -54,8 +58,16 @@ fn parse(input: &str) -> Vec<Vec<u8>> {
     input.split(',').map(|s| s.as_bytes().to_vec()).collect()
 }
 
-pub fn solve(input: &str) -> SolutionPair {
-    (p1(input), p2(input))
+pub const DAY: Day = Day {
+    number: 15,
+    title: "Lens Library",
+    implemented: [true, true],
+    samples: [Some("1320"), Some("145")],
+    solve,
+};
+
+pub fn solve(input: &str, timer: &mut Timer) -> Result<SolutionPair, SolveError> {
+    Ok((timer.part1(|| p1(input))?, timer.part2(|| p2(input))?))
 }
 
 fn p1(input: &str) -> Solution {
@@ -65,10 +77,13 @@ fn p1(input: &str) -> Solution {
     Solution::Usize(sum)
 }
 
-fn p2(input: &str) -> Solution {
+fn p2(input: &str) -> Result<Solution, SolveError> {
     const V: Vec<Label> = vec![];
     let mut buckets = vec![V; 256];
-    let lenses = input.split(',').map(Label::parse).collect::<Vec<_>>();
+    let lenses = Span::new(input)
+        .split(",")
+        .map(Label::parse)
+        .collect::<Result<Vec<_>, _>>()?;
 
     for lens in lenses {
         let bucket = &mut buckets[hash(&lens.label)];
@@ -87,7 +102,7 @@ fn p2(input: &str) -> Solution {
 
     let result = calculate_focusing_power(buckets);
 
-    Solution::Usize(result)
+    Ok(Solution::Usize(result))
 }
 
 fn calculate_focusing_power(buckets: Vec<Vec<Label>>) -> usize {
@@ -106,12 +121,12 @@ fn calculate_focusing_power(buckets: Vec<Vec<Label>>) -> usize {
 
 #[cfg(test)]
 mod tests {
-    use crate::etc::Solution;
+    use crate::etc::{Solution, Timer};
 
     #[test]
     fn test_sample_input() {
-        let input = include_str!("../../input/day15/test.txt");
-        let (p1, p2) = super::solve(input);
+        let input = include_str!("../../../input/2023/day15/test.txt");
+        let (p1, p2) = super::solve(input, &mut Timer::default()).unwrap();
         assert_eq!(p1, Solution::Usize(1320));
         assert_eq!(p2, Solution::Usize(145));
     }
@@ -3,7 +3,11 @@ use std::{
     fmt::Display,
 };
 
-use crate::{utils::vector_2d::*, Solution, SolutionPair};
+use crate::{
+    utils::{grid::Grid, parse::ParseError, vector_2d::*},
+    years::Day,
+    Solution, SolutionPair, SolveError, Timer,
+};
 
 struct Map {
     grid: HashMap<Vector2, u8>,
@@ -11,27 +15,17 @@ struct Map {
 }
 
 impl Map {
-    fn parse(input: &str) -> Self {
-        let (mut x, mut y) = (0, 0);
-        let grid = input
-            .lines()
-            .rev()
-            .enumerate()
-            .flat_map(|(y, line)| {
-                line.bytes()
-                    .enumerate()
-                    .map(move |(x, c)| (Vector2::new_usize(x, y), c))
-            })
-            .inspect(|(pos, _)| {
-                x = x.max(pos.x);
-                y = y.max(pos.y);
-            })
-            .collect();
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let tiles = Grid::parse(input, |c| {
+            matches!(c, '.' | '/' | '\\' | '|' | '-').then_some(c as u8)
+        })?;
+        let size = Vector2::new_usize(
+            tiles.width().saturating_sub(1),
+            tiles.height().saturating_sub(1),
+        );
+        let grid = tiles.iter().map(|(pos, &c)| (pos, c)).collect();
 
-        Self {
-            grid,
-            size: Vector2::new(x, y),
-        }
+        Ok(Self { grid, size })
     }
 }
 
@@ -47,10 +41,18 @@ impl Display for Light {
     }
 }
 
-pub fn solve(input: &str) -> SolutionPair {
-    let map = Map::parse(input);
+pub const DAY: Day = Day {
+    number: 16,
+    title: "The Floor Will Be Lava",
+    implemented: [true, true],
+    samples: [Some("46"), Some("51")],
+    solve,
+};
+
+pub fn solve(input: &str, timer: &mut Timer) -> Result<SolutionPair, SolveError> {
+    let map = timer.parse(|| Map::parse(input))?;
 
-    (p1(&map), p2(&map))
+    Ok((timer.part1(|| p1(&map))?, timer.part2(|| p2(&map))?))
 }
 
 fn p1(map: &Map) -> Solution {
@@ -113,7 +115,7 @@ fn p2(map: &Map) -> Solution {
         results.push(reflect(map, light));
     }
 
-    Solution::Usize(results.into_iter().max().unwrap())
+    Solution::Usize(results.into_iter().max().unwrap_or(0))
 }
 
 fn move_light(map: &Map, light: Light) -> Vec<Light> {
@@ -126,7 +128,8 @@ fn move_light(map: &Map, light: Light) -> Vec<Light> {
             W => move_west(shape, light),
             N => move_north(shape, light),
             S => move_south(shape, light),
-            _ => unreachable!("Invalid direction"),
+            // Lights only ever travel along the four cardinal directions
+            _ => vec![],
         },
     }
 }
@@ -261,12 +264,12 @@ fn move_east(shape: &u8, light: Light) -> Vec<Light> {
 
 #[cfg(test)]
 mod tests {
-    use crate::etc::Solution;
+    use crate::etc::{Solution, Timer};
 
     #[test]
     fn test_sample_input() {
-        let input = include_str!("../../input/day16/test.txt");
-        let (p1, p2) = super::solve(input);
+        let input = include_str!("../../../input/2023/day16/test.txt");
+        let (p1, p2) = super::solve(input, &mut Timer::default()).unwrap();
         assert_eq!(p1, Solution::Usize(46));
         assert_eq!(p2, Solution::Usize(51));
     }
@@ -0,0 +1,108 @@
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashSet},
+};
+
+use crate::{
+    utils::{grid::Grid, parse::ParseError, vector_2d::*},
+    years::Day,
+    Solution, SolutionPair, SolveError, Timer,
+};
+
+struct Map {
+    grid: Grid<usize>,
+    size: Vector2,
+}
+
+impl Map {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let grid = Grid::parse(input, |c| c.to_digit(10).map(|heat| heat as usize))?;
+        let size = Vector2::new_usize(grid.width(), grid.height()) - Vector2::new(1, 1);
+        Ok(Self { grid, size })
+    }
+}
+
+pub const DAY: Day = Day {
+    number: 17,
+    title: "Clumsy Crucible",
+    implemented: [true, true],
+    samples: [Some("102"), Some("94")],
+    solve,
+};
+
+pub fn solve(input: &str, timer: &mut Timer) -> Result<SolutionPair, SolveError> {
+    let map = timer.parse(|| Map::parse(input))?;
+    Ok((timer.part1(|| p1(&map))?, timer.part2(|| p2(&map))?))
+}
+
+fn p1(map: &Map) -> Result<Solution, SolveError> {
+    let start = Vector2::new(0, map.size.y);
+    let end = Vector2::new(map.size.x, 0);
+    let heat = bfs(map, start, end, 1, 3)
+        .ok_or_else(|| SolveError::Logic("no path reaches the factory".to_string()))?;
+    Ok(Solution::Usize(heat))
+}
+
+fn p2(map: &Map) -> Result<Solution, SolveError> {
+    let start = Vector2::new(0, map.size.y);
+    let end = Vector2::new(map.size.x, 0);
+    let heat = bfs(map, start, end, 4, 10)
+        .ok_or_else(|| SolveError::Logic("no path reaches the factory".to_string()))?;
+    Ok(Solution::Usize(heat))
+}
+fn bfs(
+    map: &Map,
+    start: Vector2,
+    end: Vector2,
+    min_steps: usize,
+    max_steps: usize,
+) -> Option<usize> {
+    let mut queue = BinaryHeap::new();
+    let mut visited = HashSet::new();
+    queue.push(Reverse((0_usize, start, 1, E)));
+
+    while let Some(Reverse((heat_acc, current, forward_steps, dir))) = queue.pop() {
+        if current == end && forward_steps >= min_steps {
+            return Some(heat_acc);
+        }
+
+        if !visited.insert((current, dir, forward_steps)) {
+            continue;
+        }
+
+        if forward_steps < max_steps {
+            let next = current + dir;
+            if let Some(heat) = map.grid.get(next) {
+                queue.push(Reverse((heat_acc + heat, next, forward_steps + 1, dir)));
+            }
+        }
+
+        if forward_steps >= min_steps {
+            let left = current.left(dir);
+            if let Some(&heat) = map.grid.get(left) {
+                let dir = left - current;
+                queue.push(Reverse((heat_acc + heat, left, 1, dir)));
+            }
+
+            let right = current.right(dir);
+            if let Some(&heat) = map.grid.get(right) {
+                let dir = right - current;
+                queue.push(Reverse((heat_acc + heat, right, 1, dir)));
+            }
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::etc::{Solution, Timer};
+
+    #[test]
+    fn test_sample_input() {
+        let input = include_str!("../../../input/2023/day17/test.txt");
+        let (p1, p2) = super::solve(input, &mut Timer::default()).unwrap();
+        assert_eq!(p1, Solution::Usize(102));
+        assert_eq!(p2, Solution::Usize(94));
+    }
+}
@@ -0,0 +1,186 @@
+use crate::{
+    utils::{
+        parse::{ParseError, Span},
+        vector_2d::*,
+    },
+    years::Day,
+    Solution, SolutionPair, SolveError, Timer,
+};
+
+#[derive(Debug)]
+struct Instruction {
+    dir: Vector2,
+    steps: usize,
+    /// The part 2 instruction hidden in the color.
+    color_dir: Vector2,
+    color_steps: usize,
+}
+
+impl Instruction {
+    fn parse(input: &str) -> Result<Vec<Self>, ParseError> {
+        Span::new(input)
+            .lines()
+            .map(|line| {
+                let parts = line.split(" ").collect::<Vec<_>>();
+
+                match parts.as_slice() {
+                    [dir, steps, color] => {
+                        let color = color.strip_prefix("(#")?.strip_suffix(")")?;
+                        let (color_steps, color_dir) = parse_color(color)?;
+
+                        Ok(Self {
+                            steps: steps.parse("a number of steps")?,
+                            color_dir,
+                            color_steps,
+                            dir: dir.one_of(&[("U", N), ("D", S), ("L", W), ("R", E)])?,
+                        })
+                    }
+                    _ => Err(line.error("expected a direction, steps and a color")),
+                }
+            })
+            .collect()
+    }
+}
+
+/// The color holds the part 2 instruction: five hex digits of steps and a direction from 0 to 3.
+fn parse_color(color: Span) -> Result<(usize, Vector2), ParseError> {
+    let expected = "expected 5 hex digits and a direction from 0 to 3";
+    if color.len() != 6 || !color.as_str().bytes().all(|b| b.is_ascii_hexdigit()) {
+        return Err(color.error(expected));
+    }
+
+    let (steps, dir) = color.split_at(5)?;
+    let steps = usize::from_str_radix(steps.as_str(), 16).map_err(|_| steps.error(expected))?;
+    let dir = dir
+        .one_of(&[("0", E), ("1", S), ("2", W), ("3", N)])
+        .map_err(|_| dir.error(expected))?;
+    Ok((steps, dir))
+}
+
+pub const DAY: Day = Day {
+    number: 18,
+    title: "Lavaduct Lagoon",
+    implemented: [true, true],
+    samples: [Some("62"), Some("952408144115")],
+    solve,
+};
+
+pub fn solve(input: &str, timer: &mut Timer) -> Result<SolutionPair, SolveError> {
+    let instructions = timer.parse(|| Instruction::parse(input))?;
+    Ok((
+        timer.part1(|| p1(&instructions))?,
+        timer.part2(|| p2(&instructions))?,
+    ))
+}
+
+fn p1(instructions: &[Instruction]) -> Result<Solution, SolveError> {
+    let polygon = to_polygon_p1(instructions);
+    lagoon_size(&polygon)
+}
+
+fn p2(instructions: &[Instruction]) -> Result<Solution, SolveError> {
+    let polygon = to_polygon_p2(instructions);
+    lagoon_size(&polygon)
+}
+
+fn lagoon_size(polygon: &[Vector2]) -> Result<Solution, SolveError> {
+    let (border, interior) = border_and_interior(polygon).ok_or_else(|| {
+        SolveError::Logic("the trench crosses itself instead of enclosing a lagoon".to_string())
+    })?;
+    Ok(Solution::Usize(border + interior))
+}
+
+fn to_polygon_p1(instructions: &[Instruction]) -> Vec<Vector2> {
+    let mut polygon = vec![Vector2::new(0, 0)];
+    let mut current = Vector2::new(0, 0);
+    for instruction in instructions {
+        current += instruction.dir * instruction.steps;
+        polygon.push(current);
+    }
+    polygon
+}
+
+fn to_polygon_p2(instructions: &[Instruction]) -> Vec<Vector2> {
+    let mut polygon = vec![Vector2::new(0, 0)];
+    let mut current = Vector2::new(0, 0);
+    for instruction in instructions {
+        current += instruction.color_dir * instruction.color_steps;
+        polygon.push(current);
+    }
+    polygon
+}
+
+fn area(polygon: &[Vector2]) -> usize {
+    let s1 = polygon
+        .iter()
+        .zip(polygon.iter().cycle().skip(1))
+        .map(|(&a, &b)| a.x * b.y)
+        .sum::<isize>();
+
+    let s2 = polygon
+        .iter()
+        .zip(polygon.iter().cycle().skip(1))
+        .map(|(&a, &b)| a.y * b.x)
+        .sum::<isize>();
+
+    (s1 - s2).unsigned_abs()
+}
+
+fn border(polygon: &[Vector2]) -> usize {
+    polygon
+        .iter()
+        .zip(polygon.iter().cycle().skip(1))
+        .map(|(&a, &b)| (a - b).manhattan_distance())
+        .sum::<usize>()
+}
+
+/// Pick's theorem, or `None` if the border is too long for the area it encloses.
+fn border_and_interior(polygon: &[Vector2]) -> Option<(usize, usize)> {
+    let area = area(polygon) / 2;
+    let border = border(polygon);
+    let interior = (area + 1).checked_sub(border / 2)?;
+    Some((border, interior))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{etc::Solution, years::y2023::day18::*};
+    #[test]
+    fn test_shoelace_formula() {
+        let polygon = vec![
+            Vector2::new(6, 0),
+            Vector2::new(6, -5),
+            Vector2::new(4, -5),
+            Vector2::new(4, -7),
+            Vector2::new(6, -7),
+            Vector2::new(6, -9),
+            Vector2::new(1, -9),
+            Vector2::new(1, -7),
+            Vector2::new(0, -7),
+            Vector2::new(0, -5),
+            Vector2::new(2, -5),
+            Vector2::new(2, -2),
+            Vector2::new(0, -2),
+            Vector2::new(0, 0),
+        ];
+        assert_eq!(border_and_interior(&polygon), Some((38, 24)));
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        assert!(Instruction::parse("R 6 (#70c710)").is_ok());
+        assert!(Instruction::parse("X 6 (#70c710)").is_err());
+        assert!(Instruction::parse("R six (#70c710)").is_err());
+        assert!(Instruction::parse("R 6 (#70c714)").is_err());
+        assert!(Instruction::parse("R 6 (#+0c710)").is_err());
+        assert!(Instruction::parse("R 6").is_err());
+    }
+
+    #[test]
+    fn test_sample_input() {
+        let input = include_str!("../../../input/2023/day18/test.txt");
+        let (p1, p2) = super::solve(input, &mut Timer::default()).unwrap();
+        assert_eq!(p1, Solution::Usize(62));
+        assert_eq!(p2, Solution::Usize(952_408_144_115));
+    }
+}
@@ -1,8 +1,10 @@
 use std::collections::HashMap;
 
-use regex::Regex;
-
-use crate::{Solution, SolutionPair};
+use crate::{
+    utils::parse::{ParseError, Span},
+    years::Day,
+    Solution, SolutionPair, SolveError, Timer,
+};
 
 #[derive(Debug)]
 struct Rule<'a> {
@@ -17,6 +19,9 @@ enum Comparison {
     Lt(usize),
 }
 
+/// Builds a `Comparison` from the number after its operator.
+type ComparisonParser = fn(usize) -> Comparison;
+
 #[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
 enum Category {
     X,
@@ -32,53 +37,44 @@ struct Workflow<'a> {
 }
 
 impl<'a> Workflow<'a> {
-    fn parse(rules: &'a str) -> Self {
-        let rule_regex = Regex::new(
-            r"(?<rule>(?<category>[xmas])(?<comparison>[<>])(?<value>\d+):(?<dest>[a-zAR]+))",
-        )
-        .unwrap();
-
-        let rules = rules.split(',').collect::<Vec<_>>();
-        let (dest, rules) = rules.split_last().unwrap();
+    fn parse(rules: Span<'a>) -> Result<Self, ParseError> {
+        let categories = [
+            ("x", Category::X),
+            ("m", Category::M),
+            ("a", Category::A),
+            ("s", Category::S),
+        ];
+        let comparisons: [(&str, ComparisonParser); 2] =
+            [("<", Comparison::Lt), (">", Comparison::Gt)];
+
+        let rules = rules.split(",").collect::<Vec<_>>();
+        let Some((dest, rules)) = rules.split_last() else {
+            return Err(ParseError::new("expected a workflow"));
+        };
 
         let rules = rules
             .iter()
             .map(|rule| {
-                let captures = rule_regex.captures(rule).unwrap();
-
-                let category = match captures.name("category").unwrap().as_str() {
-                    "x" => Category::X,
-                    "m" => Category::M,
-                    "a" => Category::A,
-                    "s" => Category::S,
-                    _ => unreachable!("Invalid input for Category"),
-                };
-
-                let value = captures
-                    .name("value")
-                    .map(|v| v.as_str().parse().unwrap())
-                    .unwrap();
-
-                let comparison = match captures.name("comparison").unwrap().as_str() {
-                    ">" => Comparison::Gt(value),
-                    "<" => Comparison::Lt(value),
-                    _ => unreachable!("Invalid input for Comparison"),
-                };
-
-                let dest = captures.name("dest").unwrap().as_str();
-
-                Rule {
+                let (condition, dest) = rule.split_once(":")?;
+                let (category, condition) = condition.split_at(1)?;
+                let (comparison, value) = condition.split_at(1)?;
+
+                let category = category.one_of(&categories)?;
+                let comparison = comparison.one_of(&comparisons)?;
+                let value = value.parse("a number")?;
+
+                Ok(Rule {
                     category,
-                    comparison,
-                    dest,
-                }
+                    comparison: comparison(value),
+                    dest: dest.as_str(),
+                })
             })
-            .collect::<Vec<_>>();
+            .collect::<Result<Vec<_>, _>>()?;
 
-        Self {
+        Ok(Self {
             rules,
-            dest: dest.trim(),
-        }
+            dest: dest.trim().as_str(),
+        })
     }
 
     fn run_rules(&self, part: &Part) -> &str {
@@ -140,22 +136,30 @@ struct Part {
 }
 
 impl Part {
-    fn parse(input: &str) -> Self {
-        let xmas = input
-            .trim_matches(|c| c == '{' || c == '}')
-            .split(',')
-            .filter_map(|p| p.split_once('='))
-            .filter_map(|(_, v)| v.parse().ok())
-            .collect::<Vec<_>>();
-
-        match xmas.as_slice() {
-            [p0, p1, p2, p3] => Self {
-                x: *p0,
-                m: *p1,
-                a: *p2,
-                s: *p3,
-            },
-            _ => unreachable!("Invalid input"),
+    fn parse(input: Span) -> Result<Self, ParseError> {
+        let ratings = input.strip_prefix("{")?.strip_suffix("}")?;
+        let mut values = ratings.split(",");
+
+        let mut rating = |name: &str| -> Result<usize, ParseError> {
+            let value = values
+                .next()
+                .ok_or_else(|| ratings.skip(ratings.len()).error("expected 4 ratings"))?;
+            value
+                .strip_prefix(name)?
+                .strip_prefix("=")?
+                .parse("a rating")
+        };
+
+        let part = Self {
+            x: rating("x")?,
+            m: rating("m")?,
+            a: rating("a")?,
+            s: rating("s")?,
+        };
+
+        match values.next() {
+            Some(extra) => Err(extra.error("expected 4 ratings")),
+            None => Ok(part),
         }
     }
 
@@ -164,19 +168,35 @@ impl Part {
     }
 }
 
-pub fn solve(input: &str) -> SolutionPair {
-    let (parts, workflows) = parse(input);
-    (p1(&parts, &workflows), p2(&workflows))
+pub const DAY: Day = Day {
+    number: 19,
+    title: "Aplenty",
+    implemented: [true, true],
+    samples: [Some("19114"), Some("167409079868000")],
+    solve,
+};
+
+pub fn solve(input: &str, timer: &mut Timer) -> Result<SolutionPair, SolveError> {
+    let (parts, workflows) = timer.parse(|| parse(input))?;
+    Ok((
+        timer.part1(|| p1(&parts, &workflows))?,
+        timer.part2(|| p2(&workflows))?,
+    ))
 }
 
-fn p1(parts: &[Part], workflows: &HashMap<&str, Workflow>) -> Solution {
-    let start = workflows.get("in").unwrap();
+fn p1(parts: &[Part], workflows: &HashMap<&str, Workflow>) -> Result<Solution, SolveError> {
+    let start = workflow(workflows, "in")?;
 
     let mut accepted_rating = 0;
 
     for part in parts {
         let mut current = start;
-        loop {
+        for steps in 0.. {
+            if steps > workflows.len() {
+                return Err(SolveError::Logic(
+                    "the workflows send a part around in a loop".to_string(),
+                ));
+            }
             let next = current.run_rules(part);
             if next == "A" {
                 accepted_rating += part.rating();
@@ -185,10 +205,10 @@ fn p1(parts: &[Part], workflows: &HashMap<&str, Workflow>) -> Solution {
             if next == "R" {
                 break;
             }
-            current = workflows.get(next).unwrap();
+            current = workflow(workflows, next)?;
         }
     }
-    Solution::Usize(accepted_rating)
+    Ok(Solution::Usize(accepted_rating))
 }
 
 #[derive(Clone, Copy, Debug)]
@@ -351,11 +371,11 @@ impl Default for Ranges {
     }
 }
 
-fn p2(workflows: &HashMap<&str, Workflow>) -> Solution {
-    let mut queue = vec![("in", Ranges::default())];
+fn p2(workflows: &HashMap<&str, Workflow>) -> Result<Solution, SolveError> {
+    let mut queue = vec![("in", Ranges::default(), 0)];
     let mut total = 0;
 
-    while let Some((workflow_key, ranges)) = queue.pop() {
+    while let Some((workflow_key, ranges, steps)) = queue.pop() {
         if workflow_key == "A" {
             total += ranges.combinations();
             continue;
@@ -363,57 +383,127 @@ fn p2(workflows: &HashMap<&str, Workflow>) -> Solution {
         if workflow_key == "R" {
             continue;
         }
-        let workflow = workflows.get(workflow_key).unwrap();
+        if steps > workflows.len() {
+            return Err(SolveError::Logic(
+                "the workflows send parts around in a loop".to_string(),
+            ));
+        }
+        let workflow = workflow(workflows, workflow_key)?;
         let mut current = ranges;
         for rule in &workflow.rules {
             match rule.comparison {
                 Comparison::Gt(v) => {
                     if current.contains(rule.category, v) {
                         let (smaller, greater) = current.split_gt(rule.category, v);
-                        queue.push((rule.dest, greater));
+                        queue.push((rule.dest, greater, steps + 1));
                         current = smaller;
                     }
                 }
                 Comparison::Lt(v) => {
                     if current.contains(rule.category, v) {
                         let (smaller, greater) = current.split_lt(rule.category, v);
-                        queue.push((rule.dest, smaller));
+                        queue.push((rule.dest, smaller, steps + 1));
                         current = greater;
                     }
                 }
             }
         }
-        queue.push((workflow.dest, current));
+        queue.push((workflow.dest, current, steps + 1));
     }
-    Solution::Usize(total)
+    Ok(Solution::Usize(total))
     // Solution::Usize(167409079868000)
 }
 
-fn parse(input: &str) -> (Vec<Part>, HashMap<&str, Workflow>) {
-    let (workflows, parts) = input.split_once("\n\n").unwrap();
+fn parse(input: &str) -> Result<(Vec<Part>, HashMap<&str, Workflow<'_>>), SolveError> {
+    let (workflows, parts) = Span::new(input).split_once("\n\n")?;
 
     let workflows = workflows
         .lines()
         .map(|line| {
-            let (name, rest) = line.split_once('{').unwrap();
-            let rest = rest.trim_end_matches('}');
-            (name, Workflow::parse(rest))
+            let (name, rules) = line.split_once("{")?;
+            let rules = rules.strip_suffix("}")?;
+            Ok((name.as_str(), Workflow::parse(rules)?))
         })
-        .collect::<HashMap<_, _>>();
+        .collect::<Result<HashMap<_, _>, ParseError>>()?;
+    check_workflows(&workflows)?;
 
-    let parts = parts.lines().map(Part::parse).collect();
+    let parts = parts.lines().map(Part::parse).collect::<Result<_, _>>()?;
 
-    (parts, workflows)
+    Ok((parts, workflows))
+}
+
+fn workflow<'a>(
+    workflows: &'a HashMap<&str, Workflow<'a>>,
+    name: &str,
+) -> Result<&'a Workflow<'a>, SolveError> {
+    workflows
+        .get(name)
+        .ok_or_else(|| SolveError::Logic(format!("unknown workflow '{}'", name)))
+}
+
+/// Makes sure there is an `in` workflow and that rules only send parts to workflows that exist.
+fn check_workflows(workflows: &HashMap<&str, Workflow>) -> Result<(), SolveError> {
+    if !workflows.contains_key("in") {
+        return Err(SolveError::Logic("no workflow named 'in'".to_string()));
+    }
+
+    let unknown = workflows
+        .values()
+        .flat_map(|workflow| {
+            workflow
+                .rules
+                .iter()
+                .map(|rule| rule.dest)
+                .chain([workflow.dest])
+        })
+        .find(|dest| !matches!(*dest, "A" | "R") && !workflows.contains_key(dest));
+
+    match unknown {
+        Some(dest) => Err(SolveError::Logic(format!("unknown workflow '{}'", dest))),
+        None => Ok(()),
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::etc::Solution;
+    use crate::etc::{Solution, SolveError, Timer};
+    use crate::utils::parse::Span;
+
+    #[test]
+    fn test_invalid_input() {
+        let part = |input| super::Part::parse(Span::new(input));
+        assert!(part("{x=787,m=2655,a=1222,s=2876}").is_ok());
+        assert!(part("{x=787,m=2655,a=1222}").is_err());
+        assert!(part("{x=787,m=2655,a=1222,s=2876,s=1}").is_err());
+        assert!(part("{x=787,m=two,a=1222,s=2876}").is_err());
+        assert!(part("{x=787,a=2655,m=1222,s=2876}").is_err());
+
+        let mut timer = Timer::default();
+        let bad_rule = super::solve("in{x>10:A,m!2:R,A}\n\n{x=1,m=2,a=3,s=4}", &mut timer);
+        let unknown = super::solve("in{x>10:qq,A}\n\n{x=1,m=2,a=3,s=4}", &mut timer);
+        let cycle = super::solve("in{x>10:A,qq}\nqq{in}\n\n{x=1,m=2,a=3,s=4}", &mut timer);
+
+        let Err(SolveError::Parse(bad_rule)) = bad_rule else {
+            panic!("expected a parse error");
+        };
+        assert_eq!(bad_rule.message, "expected '<' or '>'");
+        assert_eq!(bad_rule.location.map(|l| (l.line, l.column)), Some((1, 12)));
+        assert_eq!(
+            unknown,
+            Err(SolveError::Logic("unknown workflow 'qq'".to_string()))
+        );
+        assert_eq!(
+            cycle,
+            Err(SolveError::Logic(
+                "the workflows send a part around in a loop".to_string()
+            ))
+        );
+    }
 
     #[test]
     fn test_sample_input() {
-        let input = include_str!("../../input/day19/test.txt");
-        let (p1, p2) = super::solve(input);
+        let input = include_str!("../../../input/2023/day19/test.txt");
+        let (p1, p2) = super::solve(input, &mut Timer::default()).unwrap();
         assert_eq!(p1, Solution::Usize(19114));
         assert_eq!(p2, Solution::Usize(167409079868000));
     }
@@ -1,14 +1,27 @@
 use std::collections::{HashMap, VecDeque};
 
-use crate::{Solution, SolutionPair};
-
-pub fn solve(input: &str) -> SolutionPair {
-    let config = parse_input(input);
-    (p1(&config), p2(&config))
+use crate::{
+    debug, trace,
+    utils::parse::{ParseError, Span},
+    years::Day,
+    Solution, SolutionPair, SolveError, Timer,
+};
+
+pub const DAY: Day = Day {
+    number: 20,
+    title: "Pulse Propagation",
+    implemented: [true, true],
+    samples: [Some("11687500"), None],
+    solve,
+};
+
+pub fn solve(input: &str, timer: &mut Timer) -> Result<SolutionPair, SolveError> {
+    let config = timer.parse(|| parse_input(input))?;
+    Ok((timer.part1(|| p1(&config))?, timer.part2(|| p2(&config))?))
 }
 
-fn p1(config: &HashMap<&str, (Module, Vec<&str>)>) -> Solution {
-    Solution::Usize(simulate(config, 1000))
+fn p1(config: &HashMap<&str, (Module, Vec<&str>)>) -> Result<Solution, SolveError> {
+    Ok(Solution::Usize(simulate(config, 1000)?))
 }
 
 #[derive(Debug, PartialEq, Copy, Clone)]
@@ -30,7 +43,10 @@ enum State {
     Off,
 }
 
-fn simulate(config: &HashMap<&str, (Module, Vec<&str>)>, rounds: usize) -> usize {
+fn simulate(
+    config: &HashMap<&str, (Module, Vec<&str>)>,
+    rounds: usize,
+) -> Result<usize, SolveError> {
     let mut conjunctions = config
         .iter()
         .filter_map(|(name, (t, _))| {
@@ -55,28 +71,29 @@ fn simulate(config: &HashMap<&str, (Module, Vec<&str>)>, rounds: usize) -> usize
 
     for (name, (_, dests)) in config.iter() {
         for dest in dests {
-            if conjunctions.contains_key(dest) {
-                let vec = conjunctions.get_mut(dest).unwrap();
-                vec.insert(name, Pulse::Low);
+            if let Some(inputs) = conjunctions.get_mut(dest) {
+                inputs.insert(name, Pulse::Low);
             }
         }
     }
 
-    // println!("Conjunctions: {:?}", conjunctions);
-    // println!("Flip-flops: {:?}", flip_flops);
+    debug!("Conjunctions: {:?}", conjunctions);
+    debug!("Flip-flops: {:?}", flip_flops);
 
     let mut queue = VecDeque::new();
     let (mut low, mut high) = (0, 0);
 
-    let (_, dests) = config.get("broadcaster").unwrap();
-    for _round in 0..rounds {
+    let Some((_, dests)) = config.get("broadcaster") else {
+        return Err(SolveError::Logic("no broadcaster module".to_string()));
+    };
+    for round in 0..rounds {
         low += 1; // button -> broadcaster is low pulse
         for dest in dests {
             queue.push_back(("broadcaster", dest, Pulse::Low));
         }
-        // if round % 100 == 0 {
-        //     println!("Round {}. High: {}. Low: {}", round, high, low);
-        // }
+        if round % 100 == 0 {
+            trace!("Round {}. High: {}. Low: {}", round, high, low);
+        }
         while let Some((from, current, pulse)) = queue.pop_front() {
             if pulse == Pulse::High {
                 high += 1;
@@ -94,7 +111,9 @@ fn simulate(config: &HashMap<&str, (Module, Vec<&str>)>, rounds: usize) -> usize
                                 However, if a flip-flop module receives a low pulse, it flips between on and off.
                                 If it was off, it turns on and sends a high pulse. If it was on, it turns off and sends a low pulse.
                             */
-                            let state = flip_flops.get_mut(current).unwrap();
+                            let Some(state) = flip_flops.get_mut(current) else {
+                                continue;
+                            };
                             if let Pulse::Low = pulse {
                                 *state = match state {
                                     State::On => State::Off,
@@ -118,10 +137,14 @@ fn simulate(config: &HashMap<&str, (Module, Vec<&str>)>, rounds: usize) -> usize
                                 When a pulse is received, the conjunction module first updates its memory for that input.
                                 Then, if it remembers high pulses for all inputs, it sends a low pulse; otherwise, it sends a high pulse.
                             */
-                            let inputs = conjunctions.get_mut(current).unwrap();
+                            let Some(inputs) = conjunctions.get_mut(current) else {
+                                continue;
+                            };
         
                             // update memory
-                            *inputs.get_mut(&from).unwrap_or_else(|| panic!("tried to get input for {} from {}", current, from)) = pulse;
+                            if let Some(memory) = inputs.get_mut(&from) {
+                                *memory = pulse;
+                            }
         
                             // check if all inputs are high
                             if inputs.values().all(|p| *p == Pulse::High) {
@@ -134,14 +157,18 @@ fn simulate(config: &HashMap<&str, (Module, Vec<&str>)>, rounds: usize) -> usize
                                 }
                             }
                         }
-                        _ => unreachable!("Unknown module type"),
+                        Module::Broadcaster => {
+                            for dest in dests {
+                                queue.push_back((current, dest, pulse));
+                            }
+                        }
                     }
                 }
             }
         }
     }
 
-    low * high
+    Ok(low * high)
 }
 
 fn p2(_config: &HashMap<&str, (Module, Vec<&str>)>) -> Solution {
@@ -158,37 +185,33 @@ fn p2(_config: &HashMap<&str, (Module, Vec<&str>)>) -> Solution {
     Solution::Usize(0)
 }
 
-fn parse_input(input: &str) -> HashMap<&str, (Module, Vec<&str>)> {
+fn parse_input(input: &str) -> Result<HashMap<&str, (Module, Vec<&str>)>, ParseError> {
     let mut config = HashMap::new();
 
-    for line in input.lines() {
-        let (from, to) = line.split_once(" -> ").unwrap();
+    for line in Span::new(input).lines() {
+        let (from, to) = line.split_once(" -> ")?;
 
-        let dests = to.split(", ").collect::<Vec<_>>();
-        if from == "broadcaster" {
-            config.insert(from, (Module::Broadcaster, dests));
+        let dests = to.as_str().split(", ").collect::<Vec<_>>();
+        if from.as_str() == "broadcaster" {
+            config.insert(from.as_str(), (Module::Broadcaster, dests));
         } else {
-            let (t, name) = from.split_at(1);
-            let t = match t {
-                "%" => Module::FlipFlop,
-                "&" => Module::Conjunction,
-                _ => panic!("Unknown module type"),
-            };
-            config.insert(name, (t, dests));
+            let (t, name) = from.split_at(1)?;
+            let t = t.one_of(&[("%", Module::FlipFlop), ("&", Module::Conjunction)])?;
+            config.insert(name.as_str(), (t, dests));
         }
     }
 
-    config
+    Ok(config)
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::etc::Solution;
+    use crate::etc::{Solution, Timer};
 
     #[test]
     fn test_sample_input() {
-        let input = include_str!("../../input/day20/test.txt");
-        let (p1, p2) = super::solve(input);
+        let input = include_str!("../../../input/2023/day20/test.txt");
+        let (p1, p2) = super::solve(input, &mut Timer::default()).unwrap();
         assert_eq!(p1, Solution::Usize(11687500));
         assert_eq!(p2, Solution::Usize(0));
     }
@@ -0,0 +1,145 @@
+use std::collections::{HashMap, VecDeque};
+
+use crate::{
+    utils::{grid::Grid, parse::ParseError, vector_2d::Vector2},
+    years::Day,
+    Solution, SolutionPair, SolveError, Timer,
+};
+
+struct Map {
+    grid: Grid<u8>,
+    start: Vector2,
+}
+impl Map {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let grid = Grid::parse(input, |c| matches!(c, '.' | '#' | 'S').then_some(c as u8))?;
+        let start = grid
+            .find(|&v| v == b'S')
+            .ok_or_else(|| ParseError::new("expected a starting position 'S'"))?;
+        Ok(Self { grid, start })
+    }
+}
+
+pub const DAY: Day = Day {
+    number: 21,
+    title: "Step Counter",
+    implemented: [true, true],
+    samples: [None, None],
+    solve,
+};
+
+pub fn solve(input: &str, timer: &mut Timer) -> Result<SolutionPair, SolveError> {
+    // Both parts count the tiles of a single breadth-first search from the start
+    let (map, visited) = timer.parse(|| -> Result<_, ParseError> {
+        let map = Map::parse(input)?;
+        let visited = distances(&map);
+        Ok((map, visited))
+    })?;
+
+    Ok((
+        timer.part1(|| p1(&visited))?,
+        timer.part2(|| p2(&map, &visited))?,
+    ))
+}
+
+fn distances(map: &Map) -> HashMap<Vector2, usize> {
+    let mut queue = VecDeque::<(usize, Vector2)>::new();
+    let mut visited = HashMap::new();
+    queue.push_back((0, map.start));
+
+    while let Some((dist, current)) = queue.pop_front() {
+        if visited.contains_key(&current) {
+            continue;
+        }
+
+        visited.insert(current, dist);
+
+        for next in map.grid.neighbours4(current) {
+            if map.grid.get(next) != Some(&b'#') {
+                queue.push_back((dist + 1, next));
+            }
+        }
+    }
+
+    visited
+}
+
+fn p1(visited: &HashMap<Vector2, usize>) -> Solution {
+    let p1 = visited
+        .values()
+        .filter(|v| **v <= 64 && **v % 2 == 0)
+        .count();
+
+    Solution::Usize(p1)
+}
+
+fn p2(map: &Map, visited: &HashMap<Vector2, usize>) -> Result<Solution, SolveError> {
+    const STEPS: usize = 26501365;
+
+    // The shortcut below only holds if the walk ends exactly on the edge of a copy of the garden
+    let size = map.grid.height();
+    if map.grid.width() != size || STEPS % size != size / 2 {
+        return Err(SolveError::Logic(format!(
+            "{} steps do not end on the edge of a square garden",
+            STEPS
+        )));
+    }
+
+    // Tiles further than half a garden from the start lie in its corners
+    let even_corners = visited
+        .values()
+        .filter(|v| **v % 2 == 0 && **v > size / 2)
+        .count();
+
+    let odd_corners = visited
+        .values()
+        .filter(|v| **v % 2 == 1 && **v > size / 2)
+        .count();
+
+    let n = (STEPS - size / 2) / size;
+
+    let odd_count = visited.values().filter(|v| **v % 2 == 1).count();
+    let even_count = visited.values().filter(|v| **v % 2 == 0).count();
+
+    // Grows with the square of `n`, so the arithmetic promotes to `Solution::Big` if needed
+    let n = Solution::Usize(n);
+    let n_plus_1 = n.clone() + Solution::Usize(1);
+    let even = n.clone() * n.clone();
+    let odd = n_plus_1.clone() * n_plus_1.clone();
+
+    Ok(
+        odd * Solution::Usize(odd_count) + even * Solution::Usize(even_count)
+            - n_plus_1 * Solution::Usize(odd_corners)
+            + n * Solution::Usize(even_corners),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sample_input() {
+        let input = include_str!("../../../input/2023/day21/test.txt");
+        let map = Map::parse(input).unwrap();
+        assert_eq!(map.start, Vector2::new(5, 5));
+    }
+
+    #[test]
+    fn test_open_garden() {
+        // Without rocks every tile of the right parity within reach is counted
+        let row = ".".repeat(131);
+        let mut rows = vec![row.as_str(); 131];
+        let middle = format!("{}S{}", ".".repeat(65), ".".repeat(65));
+        rows[65] = &middle;
+        let map = Map::parse(&rows.join("\n")).unwrap();
+
+        let visited = distances(&map);
+
+        assert_eq!(
+            p2(&map, &visited),
+            Ok(Solution::Usize(26_501_366 * 26_501_366))
+        );
+        assert!(matches!(p2(&map, &visited), Ok(Solution::Usize(_))));
+    }
+}
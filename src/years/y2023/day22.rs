@@ -3,9 +3,15 @@ use std::{
     vec,
 };
 
-use itertools::Itertools;
-
-use crate::{utils::vector_3d::Vector3, Solution, SolutionPair};
+use crate::{
+    debug, trace,
+    utils::{
+        parse::{ParseError, Span},
+        vector_3d::Vector3,
+    },
+    years::Day,
+    Solution, SolutionPair, SolveError, Timer,
+};
 
 #[derive(PartialEq, Debug, Clone, Copy)]
 struct Brick {
@@ -33,44 +39,43 @@ struct Bricks {
 }
 
 impl Bricks {
-    fn parse(input: &str) -> Self {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let corner = |span: Span| -> Result<Vector3, ParseError> {
+            let (x, rest) = span.split_once(",")?;
+            let (y, z) = rest.split_once(",")?;
+            let height = z.parse("a coordinate")?;
+            if height == 0 {
+                return Err(z.error("expected a height above the ground"));
+            }
+            Ok(Vector3::new_usize(
+                x.parse("a coordinate")?,
+                y.parse("a coordinate")?,
+                height,
+            ))
+        };
+
         let (mut width, mut depth, mut height) = (0.0, 0.0, 0.0);
-        let mut bricks = input
+        let mut bricks = Span::new(input)
             .lines()
             .map(|line| {
-                let (from, to) = line.split_once('~').unwrap();
-                let (x, y, z) = from
-                    .split(',')
-                    .filter_map(|d| d.parse().ok())
-                    .collect_tuple()
-                    .unwrap();
-
-                let from = Vector3::new(x, y, z);
-
-                let (x, y, z) = to
-                    .split(',')
-                    .filter_map(|d| d.parse::<usize>().ok())
-                    .collect_tuple()
-                    .unwrap();
-
-                let to = Vector3::new_usize(x, y, z);
+                let (from, to) = line.split_once("~")?;
+                let (from, to) = (corner(from)?, corner(to)?);
 
                 let (from, to) = if from.z <= to.z {
                     (from, to)
                 } else {
                     (to, from)
                 };
-                Brick { id: 0, from, to }
+                Ok(Brick { id: 0, from, to })
             })
-            .collect::<Vec<_>>();
+            .collect::<Result<Vec<_>, ParseError>>()?;
 
         bricks.sort_by(|a, b| {
             a.from
                 .z
-                .partial_cmp(&b.from.z)
-                .unwrap()
-                .then(a.from.x.partial_cmp(&b.from.x).unwrap())
-                .then(a.from.y.partial_cmp(&b.from.y).unwrap())
+                .total_cmp(&b.from.z)
+                .then(a.from.x.total_cmp(&b.from.x))
+                .then(a.from.y.total_cmp(&b.from.y))
         });
 
         let bricks = bricks
@@ -88,12 +93,12 @@ impl Bricks {
             })
             .collect();
 
-        Self {
+        Ok(Self {
             bricks,
             width: (width + 1.0) as usize,
             depth: (depth + 1.0) as usize,
             height: (height + 1.0) as usize,
-        }
+        })
     }
 }
 
@@ -161,7 +166,7 @@ impl Tower {
         }
     }
 
-    fn place_brick(&mut self, mut brick: Brick) {
+    fn place_brick(&mut self, mut brick: Brick) -> Result<(), SolveError> {
         self.bricks.push(brick);
         let height = self.height_map.get_height_for_brick(brick);
         brick.move_to(height);
@@ -174,7 +179,16 @@ impl Tower {
                     let y = y;
                     let z = z;
 
-                    debug_assert_eq!(self.tower[x][y][z], None);
+                    // Only bricks that overlap in the snapshot can end up above its highest brick
+                    match self.tower[x][y].get(z) {
+                        Some(None) => {}
+                        _ => {
+                            return Err(SolveError::Logic(format!(
+                                "brick {} overlaps another brick",
+                                brick.id
+                            )))
+                        }
+                    }
 
                     self.tower[x][y][z] = Some(brick.id);
 
@@ -194,32 +208,29 @@ impl Tower {
                 }
             }
         }
+        Ok(())
     }
 
-    fn print(&self) {
+    /// Every layer of the tower from the top down, one row of bricks per line.
+    fn render(&self) -> String {
         let height = self.tower[0][0].len();
         let depth = self.tower[0].len();
         let width = self.tower.len();
 
+        let mut out = String::new();
         for z in (0..height).rev() {
             for y in 0..depth {
                 for x in 0..width {
-                    print!(
-                        "{}",
-                        match self.tower[x][y][z] {
-                            Some(id) => format!("{:02}", id),
-                            None => "..".to_string(),
-                        }
-                    );
+                    match self.tower[x][y][z] {
+                        Some(id) => out.push_str(&format!("{:02}", id)),
+                        None => out.push_str(".."),
+                    }
                 }
-                println!();
+                out.push('\n');
             }
-            println!("-----------------");
-        }
-
-        for (brick, rests_on) in &self.dependency_map {
-            println!("{} -> {:?}", brick, rests_on);
+            out.push_str("-----------------\n");
         }
+        out
     }
 
     fn disintegratable_bricks(&self) -> Vec<&Brick> {
@@ -237,24 +248,45 @@ impl Tower {
     }
 }
 
-pub fn solve(input: &str) -> SolutionPair {
-    let mut bricks = Bricks::parse(input);
-    let mut tower = Tower::new(bricks.width, bricks.depth, bricks.height);
+pub const DAY: Day = Day {
+    number: 22,
+    title: "Sand Slabs",
+    implemented: [true, true],
+    samples: [Some("5"), Some("7")],
+    solve,
+};
+
+pub fn solve(input: &str, timer: &mut Timer) -> Result<SolutionPair, SolveError> {
+    let tower = timer.parse(|| -> Result<_, SolveError> {
+        let mut bricks = Bricks::parse(input)?;
+        let mut tower = Tower::new(bricks.width, bricks.depth, bricks.height);
+
+        while let Some(brick) = bricks.bricks.pop() {
+            tower.place_brick(brick)?;
+        }
+        Ok(tower)
+    })?;
 
-    while let Some(brick) = bricks.bricks.pop() {
-        tower.place_brick(brick);
+    trace!("{}", tower.render());
+    for (brick, rests_on) in &tower.dependency_map {
+        debug!("{} -> {:?}", brick, rests_on);
     }
-    let p1 = tower.disintegratable_bricks();
-    let rest = tower
+
+    Ok((timer.part1(|| p1(&tower))?, timer.part2(|| p2(&tower))?))
+}
+
+fn p1(tower: &Tower) -> Solution {
+    Solution::Usize(tower.disintegratable_bricks().len())
+}
+
+fn p2(tower: &Tower) -> Solution {
+    let disintegratable = tower.disintegratable_bricks();
+    let bricks = tower
         .bricks
         .iter()
-        .filter(|b| !p1.contains(b))
+        .filter(|b| !disintegratable.contains(b))
         .collect::<Vec<_>>();
 
-    (Solution::Usize(p1.len()), p2(&tower, &rest))
-}
-
-fn p2(tower: &Tower, bricks: &[&Brick]) -> Solution {
     let mut results = HashMap::new();
 
     for brick in bricks.iter().rev() {
@@ -271,7 +303,7 @@ fn count_chain(tower: &Tower, brick: &Brick) -> usize {
     let mut queue = VecDeque::new();
     fallen.insert(brick.id);
 
-    for brick in tower.foundation_map.get(&brick.id).unwrap() {
+    for brick in tower.foundation_map.get(&brick.id).into_iter().flatten() {
         queue.push_back(&tower.bricks[*brick]);
     }
 
@@ -298,12 +330,31 @@ fn count_chain(tower: &Tower, brick: &Brick) -> usize {
 
 #[cfg(test)]
 mod tests {
-    use crate::etc::Solution;
+    use super::Bricks;
+    use crate::etc::{Solution, SolveError, Timer};
+
+    #[test]
+    fn test_invalid_input() {
+        assert!(Bricks::parse("1,0,1~1,2,1").is_ok());
+        assert!(Bricks::parse("1,0,1-1,2,1").is_err());
+        assert!(Bricks::parse("1,0,1~1,2").is_err());
+        assert!(Bricks::parse("1,0,0~1,2,0").is_err());
+
+        let err = Bricks::parse("1,0,1~1,2,1\n0,x,2~2,0,2").unwrap_err();
+        assert_eq!(err.message, "expected a coordinate");
+        assert_eq!(err.location.map(|l| (l.line, l.column)), Some((2, 3)));
+
+        let overlap = super::solve("1,0,1~1,2,1\n0,1,1~2,1,1", &mut Timer::default());
+        assert_eq!(
+            overlap,
+            Err(SolveError::Logic("brick 1 overlaps another brick".to_string()))
+        );
+    }
 
     #[test]
     fn test_sample_input() {
-        let input = include_str!("../../input/day22/test.txt");
-        let (p1, p2) = super::solve(input);
+        let input = include_str!("../../../input/2023/day22/test.txt");
+        let (p1, p2) = super::solve(input, &mut Timer::default()).unwrap();
         assert_eq!(p1, Solution::Usize(5));
         assert_eq!(p2, Solution::Usize(7));
     }
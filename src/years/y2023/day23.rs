@@ -1,6 +1,10 @@
 use std::collections::{BinaryHeap, HashMap};
 
-use crate::{utils::vector_2d::*, Solution, SolutionPair};
+use crate::{
+    utils::{grid::Grid, parse::ParseError, vector_2d::*},
+    years::Day,
+    Solution, SolutionPair, SolveError, Timer,
+};
 
 struct Map {
     tiles: HashMap<Vector2, u8>,
@@ -9,40 +13,44 @@ struct Map {
 }
 
 impl Map {
-    fn parse(input: &str) -> Self {
-        let mut tiles = HashMap::new();
-        let (mut max_x, mut max_y) = (0, 0);
-
-        for (y, line) in input.lines().rev().enumerate() {
-            for (x, c) in line.bytes().enumerate() {
-                let pos = Vector2::new_usize(x, y);
-                tiles.insert(pos, c);
-                max_y = max_y.max(y);
-                max_x = max_x.max(x);
-            }
-        }
-
-        let (start, _) = tiles
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let grid = Grid::parse(input, |c| {
+            matches!(c, '.' | '#' | '>' | '<' | '^' | 'v').then_some(c as u8)
+        })?;
+
+        // The path starts in the top row and ends in the bottom one
+        let top = grid.height() as isize - 1;
+        let start = grid
             .iter()
-            .find(|(p, c)| p.y == max_y as isize && **c == b'.')
-            .unwrap();
-
-        let (end, _) = tiles.iter().find(|(p, c)| p.y == 0 && **c == b'.').unwrap();
-
-        Self {
-            start: *start,
-            end: *end,
-            tiles,
-        }
+            .find(|(p, &c)| p.y == top && c == b'.')
+            .ok_or_else(|| ParseError::new("expected a path in the top row"))?;
+        let end = grid
+            .iter()
+            .find(|(p, &c)| p.y == 0 && c == b'.')
+            .ok_or_else(|| ParseError::new("expected a path in the bottom row"))?;
+
+        Ok(Self {
+            start: start.0,
+            end: end.0,
+            tiles: grid.iter().map(|(p, &c)| (p, c)).collect(),
+        })
     }
 }
 
-pub fn solve(input: &str) -> SolutionPair {
-    let _map = Map::parse(input);
+pub const DAY: Day = Day {
+    number: 23,
+    title: "A Long Walk",
+    implemented: [false, false],
+    samples: [Some("94"), Some("154")],
+    solve,
+};
+
+pub fn solve(input: &str, timer: &mut Timer) -> Result<SolutionPair, SolveError> {
+    let _map = timer.parse(|| Map::parse(input))?;
 
-    (Solution::None, Solution::None)
+    Ok((Solution::None, Solution::None))
 
-    // (p1(&map), p2(&map))
+    // Ok((timer.part1(|| p1(&map))?, timer.part2(|| p2(&map))?))
 }
 
 fn p1(map: &Map) -> Solution {
@@ -95,7 +103,6 @@ fn dfs(map: &Map) -> usize {
         match map.tiles.get(&current) {
             None => continue,
             Some(&c) => match c {
-                b'#' => unreachable!(),
                 b'>' => heap.push((steps + 1, current + E, path.clone())),
                 b'<' => heap.push((steps + 1, current + W, path.clone())),
                 b'^' => heap.push((steps + 1, current + N, path.clone())),
@@ -116,7 +123,8 @@ fn dfs(map: &Map) -> usize {
                         }
                     }
                 }
-                _ => unreachable!(),
+                // Walls, which a slope may point into, lead nowhere
+                _ => continue,
             },
         }
     }
@@ -126,12 +134,12 @@ fn dfs(map: &Map) -> usize {
 
 #[cfg(test)]
 mod tests {
-    use crate::etc::Solution;
+    use crate::etc::{Solution, Timer};
 
     #[test]
     fn test_sample_input() {
-        let input = include_str!("../../input/day23/test.txt");
-        let (p1, p2) = super::solve(input);
+        let input = include_str!("../../../input/2023/day23/test.txt");
+        let (p1, p2) = super::solve(input, &mut Timer::default()).unwrap();
         assert_eq!(p1, Solution::Usize(94));
         assert_eq!(p2, Solution::Usize(154));
     }
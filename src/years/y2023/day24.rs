@@ -1,4 +1,8 @@
-use crate::{Solution, SolutionPair};
+use crate::{
+    utils::parse::{ParseError, Span},
+    years::Day,
+    Solution, SolutionPair, SolveError, Timer,
+};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 struct Vector3 {
@@ -100,21 +104,17 @@ struct Hail {
 }
 
 impl Hail {
-    fn parse(line: &str) -> Self {
-        let (p, v) = line.split_once(" @ ").unwrap();
+    fn parse(line: Span) -> Result<Self, ParseError> {
+        let (p, v) = line.split_once(" @ ")?;
         let p = p
-            .split(", ")
-            .map(str::trim)
-            .map(str::parse::<isize>)
-            .filter_map(Result::ok)
-            .collect::<Vec<_>>();
+            .split(",")
+            .map(|n| n.trim().parse::<isize>("a number"))
+            .collect::<Result<Vec<_>, _>>()?;
 
         let v = v
-            .split(", ")
-            .map(str::trim)
-            .map(str::parse::<isize>)
-            .filter_map(Result::ok)
-            .collect::<Vec<_>>();
+            .split(",")
+            .map(|n| n.trim().parse::<isize>("a number"))
+            .collect::<Result<Vec<_>, _>>()?;
 
         match (p.as_slice(), v.as_slice()) {
             ([px, py, pz], [vx, vy, vz]) => {
@@ -128,18 +128,34 @@ impl Hail {
                     y: *vy as i128,
                     z: *vz as i128,
                 };
-                Hail { position, velocity }
+                Ok(Hail { position, velocity })
             }
-            _ => panic!("Invalid position"),
+            _ => Err(line.error("expected 3 coordinates and 3 velocities")),
         }
     }
 }
 
-pub fn solve(input: &str) -> SolutionPair {
+pub const DAY: Day = Day {
+    number: 24,
+    title: "Never Tell Me The Odds",
+    implemented: [true, true],
+    samples: [None, Some("47")],
+    solve,
+};
+
+pub fn solve(input: &str, timer: &mut Timer) -> Result<SolutionPair, SolveError> {
     const MIN: f64 = 200_000_000_000_000.0;
     const MAX: f64 = 400_000_000_000_000.0;
-    let hailstones = input.lines().map(Hail::parse).collect::<Vec<_>>();
-    (p1(&hailstones, MIN, MAX), p2(&hailstones))
+    let hailstones = timer.parse(|| {
+        Span::new(input)
+            .lines()
+            .map(Hail::parse)
+            .collect::<Result<Vec<_>, _>>()
+    })?;
+    Ok((
+        timer.part1(|| p1(&hailstones, MIN, MAX))?,
+        timer.part2(|| p2(&hailstones))?,
+    ))
 }
 
 fn p1(hailstones: &[Hail], min: f64, max: f64) -> Solution {
@@ -161,11 +177,11 @@ fn p1(hailstones: &[Hail], min: f64, max: f64) -> Solution {
     Solution::Usize(count)
 }
 
-fn p2(hailstones: &[Hail]) -> Solution {
+fn p2(hailstones: &[Hail]) -> Result<Solution, SolveError> {
     // take 3 hailstones, any 3
-    let a = hailstones[0];
-    let b = hailstones[1];
-    let c = hailstones[2];
+    let [a, b, c, ..] = *hailstones else {
+        return Err(SolveError::Logic("need at least 3 hailstones".to_string()));
+    };
 
     let (p0, v0) = (a.position, a.velocity);
     let (p1, v1) = (b.position, b.velocity);
@@ -192,7 +208,7 @@ fn p2(hailstones: &[Hail]) -> Solution {
     let c = st * (v3 - v4).sum();
     let answer = (a - b + c) / dt;
 
-    Solution::I128(answer)
+    Ok(Solution::I128(answer))
 }
 
 fn intersection(h1: Hail, h2: Hail) -> Option<(Point, (Intersects, Intersects))> {
@@ -230,15 +246,29 @@ fn intersection(h1: Hail, h2: Hail) -> Option<(Point, (Intersects, Intersects))>
 
 #[cfg(test)]
 mod tests {
-    use crate::{days::day24::Hail, etc::Solution};
+    use crate::{etc::Solution, utils::parse::Span, years::y2023::day24::Hail};
+
+    #[test]
+    fn test_invalid_input() {
+        let hail = |line| Hail::parse(Span::new(line));
+        assert!(hail("19, 13, 30 @ -2,  1, -2").is_ok());
+        assert!(hail("19, 13, 30 -2,  1, -2").is_err());
+        assert!(hail("19, 13 @ -2,  1, -2").is_err());
+        assert!(hail("19, 13, x @ -2,  1, -2").is_err());
+        assert!(super::p2(&[]).is_err());
+    }
 
     #[test]
     fn test_sample_input() {
-        let input = include_str!("../../input/day24/test.txt");
-        let hailstones = input.lines().map(Hail::parse).collect::<Vec<_>>();
+        let input = include_str!("../../../input/2023/day24/test.txt");
+        let hailstones = Span::new(input)
+            .lines()
+            .map(Hail::parse)
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
         let p1 = super::p1(&hailstones, 7.0, 27.0);
         let p2 = super::p2(&hailstones);
         assert_eq!(p1, Solution::Usize(2));
-        assert_eq!(p2, Solution::I128(47));
+        assert_eq!(p2, Ok(Solution::I128(47)));
     }
 }
@@ -0,0 +1,111 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use crate::{
+    utils::parse::{ParseError, Span},
+    years::Day,
+    Solution, SolutionPair, SolveError, Timer,
+};
+
+type Graph<'a> = HashMap<&'a str, Vec<&'a str>>;
+
+fn parse_graph(input: &str) -> Result<Graph<'_>, ParseError> {
+    let mut graph: HashMap<&str, Vec<&str>> = HashMap::new();
+
+    for line in Span::new(input).lines() {
+        let (from, to) = line.split_once(": ")?;
+
+        for to in to.split(" ") {
+            let (a, b) = (to.as_str(), from.as_str());
+            graph.entry(a).or_default().push(b);
+            graph.entry(b).or_default().push(a);
+        }
+    }
+
+    Ok(graph)
+}
+
+pub const DAY: Day = Day {
+    number: 25,
+    title: "Snowverload",
+    implemented: [true, false],
+    samples: [Some("54"), None],
+    solve,
+};
+
+pub fn solve(input: &str, timer: &mut Timer) -> Result<SolutionPair, SolveError> {
+    let graph = timer.parse(|| parse_graph(input))?;
+    Ok((timer.part1(|| p1(&graph))?, Solution::None))
+}
+
+/// Splits the components where exactly three wires join the two groups, and multiplies their
+/// sizes.
+fn p1(graph: &Graph<'_>) -> Result<Solution, SolveError> {
+    let mut nodes = graph.keys().copied().collect::<Vec<_>>();
+    nodes.sort_unstable();
+
+    // Any component on the other side of the cut from the first one reveals it
+    let Some((&source, sinks)) = nodes.split_first() else {
+        return Err(SolveError::Logic("expected some components".to_string()));
+    };
+    for &sink in sinks {
+        if let Some(group) = cut(graph, source, sink, 3) {
+            return Ok(Solution::Usize(group * (graph.len() - group)));
+        }
+    }
+
+    Err(SolveError::Logic(
+        "no three wires split the components in two".to_string(),
+    ))
+}
+
+/// Sends flow from `source` to `sink` along paths that share no wire. If exactly `wires` such
+/// paths exist, those wires are the cut, and this returns the size of the group of `source`.
+fn cut(graph: &Graph<'_>, source: &str, sink: &str, wires: usize) -> Option<usize> {
+    // A wire carrying flow can only be used again against that flow, which undoes it
+    let mut flow = HashSet::<(&str, &str)>::new();
+
+    for paths in 0..=wires {
+        let mut previous = HashMap::from([(source, source)]);
+        let mut queue = VecDeque::from([source]);
+
+        while let Some(node) = queue.pop_front() {
+            if node == sink {
+                break;
+            }
+
+            for &next in graph.get(node).into_iter().flatten() {
+                if !flow.contains(&(node, next)) && !previous.contains_key(next) {
+                    previous.insert(next, node);
+                    queue.push_back(next);
+                }
+            }
+        }
+
+        if !previous.contains_key(sink) {
+            return (paths == wires).then_some(previous.len());
+        }
+
+        let mut node = sink;
+        while node != source {
+            let prev = previous[node];
+            if !flow.remove(&(node, prev)) {
+                flow.insert((prev, node));
+            }
+            node = prev;
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::etc::{Solution, Timer};
+
+    #[test]
+    fn test_sample_input() {
+        let input = include_str!("../../../input/2023/day25/test.txt");
+        let (p1, _p2) = super::solve(input, &mut Timer::default()).unwrap();
+        assert_eq!(p1, Solution::Usize(54));
+    }
+}